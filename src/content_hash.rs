@@ -0,0 +1,269 @@
+//! [Dropbox Content Hash](https://www.dropbox.com/developers/reference/content-hash)
+//!
+//! Dropbox computes the `content_hash` of a file by splitting it into 4 MiB blocks, hashing each
+//! block with SHA-256, concatenating the block hashes and hashing that concatenation with
+//! SHA-256 again. Comparing a local hash with the remote one avoids transferring unchanged files.
+//!
+
+use sha2::{Sha256, Digest};
+
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+/// The size of the blocks the content is split into before hashing.
+pub const BLOCK_SIZE: usize = 4 * 1024 * 1024;
+
+/// An incremental hasher computing the Dropbox content hash.
+///
+/// Bytes can be fed through `update` or through its `io::Write` implementation, which makes it
+/// usable with `io::copy`.
+#[derive(Clone)]
+pub struct ContentHasher {
+    overall: Sha256,
+    block: Sha256,
+    block_pos: usize,
+}
+
+impl ContentHasher {
+    pub fn new() -> ContentHasher {
+        ContentHasher {
+            overall: Sha256::default(),
+            block: Sha256::default(),
+            block_pos: 0,
+        }
+    }
+
+    /// Feeds `data` into the hasher.
+    pub fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            if self.block_pos == BLOCK_SIZE {
+                self.finish_block();
+            }
+            let len = ::std::cmp::min(BLOCK_SIZE - self.block_pos, data.len());
+            self.block.input(&data[..len]);
+            self.block_pos += len;
+            data = &data[len..];
+        }
+    }
+
+    /// Consumes the hasher and returns the content hash as a lowercase hex string, the format
+    /// used by the `content_hash` field of file metadata.
+    pub fn finish(mut self) -> String {
+        if self.block_pos > 0 {
+            self.finish_block();
+        }
+        to_hex(&self.overall.result())
+    }
+
+    fn finish_block(&mut self) {
+        let block = ::std::mem::replace(&mut self.block, Sha256::default());
+        self.overall.input(&block.result());
+        self.block_pos = 0;
+    }
+}
+
+impl Default for ContentHasher {
+    fn default() -> ContentHasher {
+        ContentHasher::new()
+    }
+}
+
+impl Write for ContentHasher {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Computes the content hash of everything that can be read from `reader`.
+pub fn hash_reader<R: Read>(reader: &mut R) -> io::Result<String> {
+    let mut hasher = ContentHasher::new();
+    io::copy(reader, &mut hasher)?;
+    Ok(hasher.finish())
+}
+
+/// Computes the content hash of the file at `path`.
+pub fn hash_file<P: AsRef<Path>>(path: P) -> io::Result<String> {
+    let mut file = File::open(path)?;
+    hash_reader(&mut file)
+}
+
+/// The integrity error returned when streamed content doesn't match its expected content hash.
+///
+/// It is surfaced as the payload of an `io::Error` of kind `InvalidData` since it is raised while
+/// reading. Use `ContentHashMismatch::from_io_error` to recover it.
+#[derive(PartialEq,Eq,Debug,Clone)]
+pub struct ContentHashMismatch {
+    pub expected: String,
+    pub actual: String,
+}
+
+impl ContentHashMismatch {
+    pub fn from_io_error(error: &io::Error) -> Option<&ContentHashMismatch> {
+        error.get_ref().and_then(|e| e.downcast_ref::<ContentHashMismatch>())
+    }
+}
+
+impl fmt::Display for ContentHashMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "content hash mismatch: expected {} but got {}",
+               self.expected,
+               self.actual)
+    }
+}
+
+impl Error for ContentHashMismatch {
+    fn description(&self) -> &str {
+        "content hash mismatch"
+    }
+}
+
+/// A reader hashing the bytes read from `inner` and checking them against an expected content
+/// hash once `inner` reaches EOF.
+///
+/// The EOF read fails with a `ContentHashMismatch` if the hashes differ, so content is verified
+/// without a second pass over it.
+pub struct VerifyingReader<R> {
+    inner: R,
+    expected: String,
+    hasher: Option<ContentHasher>,
+    mismatch: Option<ContentHashMismatch>,
+}
+
+impl<R: Read> VerifyingReader<R> {
+    pub fn new(inner: R, expected: &str) -> VerifyingReader<R> {
+        VerifyingReader {
+            inner: inner,
+            expected: expected.to_lowercase(),
+            hasher: Some(ContentHasher::new()),
+            mismatch: None,
+        }
+    }
+}
+
+impl<R: Read> Read for VerifyingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if let Some(ref mismatch) = self.mismatch {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, mismatch.clone()));
+        }
+
+        let read = self.inner.read(buf)?;
+        if read > 0 {
+            if let Some(ref mut hasher) = self.hasher {
+                hasher.update(&buf[..read]);
+            }
+        } else if buf.is_empty() {
+            // Reading into an empty buffer returns 0 without meaning EOF
+        } else if let Some(hasher) = self.hasher.take() {
+            let actual = hasher.finish();
+            if actual != self.expected {
+                let mismatch = ContentHashMismatch {
+                    expected: self.expected.clone(),
+                    actual: actual,
+                };
+                self.mismatch = Some(mismatch.clone());
+                return Err(io::Error::new(io::ErrorKind::InvalidData, mismatch));
+            }
+        }
+        Ok(read)
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Expected hashes computed with the algorithm of the reference implementation linked from the
+    // Dropbox documentation
+    fn reference_content(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i % 251) as u8).collect()
+    }
+
+    #[test]
+    fn test_empty_content_hash() {
+        assert_eq!(ContentHasher::new().finish(),
+                   "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+    }
+
+    #[test]
+    fn test_single_block_content_hash() {
+        let mut hasher = ContentHasher::new();
+        hasher.update(b"abc");
+        assert_eq!(hasher.finish(),
+                   "4f8b42c22dd3729b519ba6f68d2da7cc5b2d606d05daed5ad5128cc03e6c6358");
+    }
+
+    #[test]
+    fn test_block_boundaries_content_hash() {
+        let mut exact = ContentHasher::new();
+        exact.update(&vec![b'a'; BLOCK_SIZE]);
+        assert_eq!(exact.finish(),
+                   "907a506cf5e706bda5c7a29b43c9c65d8344bd2fa2f22339b359c214812af5a1");
+
+        let mut one_over = ContentHasher::new();
+        one_over.update(&vec![b'a'; BLOCK_SIZE + 1]);
+        assert_eq!(one_over.finish(),
+                   "5f858b62ccd88447586305aec6fd53c96747cfebf527cbba129a6dfed47d9624");
+    }
+
+    #[test]
+    fn test_incremental_writes_content_hash() {
+        let content = reference_content(2 * BLOCK_SIZE + 12345);
+        let expected = "1e4187d74c09ac5ecba418360e3aff8c86fed9e00ece62a4b8383a72b85f2cee";
+
+        let mut hasher = ContentHasher::new();
+        for chunk in content.chunks(1_000_003) {
+            hasher.write_all(chunk).expect("error writing to hasher");
+        }
+        assert_eq!(hasher.finish(), expected);
+
+        let hashed = hash_reader(&mut content.as_slice()).expect("error hashing reader");
+        assert_eq!(hashed, expected);
+    }
+
+    #[test]
+    fn test_verifying_reader() {
+        let content = reference_content(2 * BLOCK_SIZE + 12345);
+        let expected = "1e4187d74c09ac5ecba418360e3aff8c86fed9e00ece62a4b8383a72b85f2cee";
+
+        let mut reader = VerifyingReader::new(content.as_slice(), expected);
+        let mut read = Vec::new();
+        reader.read_to_end(&mut read).expect("error reading verified content");
+        assert_eq!(read, content);
+    }
+
+    #[test]
+    fn test_verifying_reader_empty_buffer() {
+        let abc_hash = "4f8b42c22dd3729b519ba6f68d2da7cc5b2d606d05daed5ad5128cc03e6c6358";
+
+        let mut reader = VerifyingReader::new(&b"abd"[..], abc_hash);
+        assert_eq!(reader.read(&mut []).expect("error reading into an empty buffer"), 0);
+        let error = reader.read_to_end(&mut Vec::new()).unwrap_err();
+        assert!(ContentHashMismatch::from_io_error(&error).is_some());
+    }
+
+    #[test]
+    fn test_verifying_reader_mismatch() {
+        let abc_hash = "4f8b42c22dd3729b519ba6f68d2da7cc5b2d606d05daed5ad5128cc03e6c6358";
+
+        let mut reader = VerifyingReader::new(&b"abd"[..], abc_hash);
+        let error = reader.read_to_end(&mut Vec::new()).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        let mismatch = ContentHashMismatch::from_io_error(&error).expect("expected a mismatch");
+        assert_eq!(mismatch.expected, abc_hash);
+        assert!(reader.read(&mut [0; 16]).is_err());
+    }
+}
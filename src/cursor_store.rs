@@ -0,0 +1,205 @@
+//! Checkpointing of list cursors so that long listings and watchers can resume after a restart.
+//!
+//! A `PaginatedIter` or `FolderWatcher` given a store with `checkpoint` loads the cursor saved
+//! under its key and saves the cursor again each time it has handed out everything fetched with
+//! the previous one.
+//!
+
+use chrono::{DateTime, Utc};
+use serde_json;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+/// A cursor saved by a `CursorStore`.
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct StoredCursor {
+    pub value: String,
+    /// The RFC 3339 timestamp after which Dropbox no longer accepts the cursor, for the endpoints
+    /// returning one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<String>,
+}
+
+impl StoredCursor {
+    pub fn new(value: &str, expiration: Option<&str>) -> StoredCursor {
+        StoredCursor {
+            value: value.to_owned(),
+            expiration: expiration.map(|x| x.to_owned()),
+        }
+    }
+
+    /// Whether the cursor expired at `now`. Cursors without an expiration, or with one which
+    /// can't be parsed, never expire.
+    pub fn is_expired_at(&self, now: DateTime<Utc>) -> bool {
+        match self.expiration {
+            Some(ref expiration) => {
+                match DateTime::parse_from_rfc3339(expiration) {
+                    Ok(expiration) => expiration <= now,
+                    Err(_) => {
+                        warn!("[CursorStore] couldn't parse cursor expiration {}", expiration);
+                        false
+                    }
+                }
+            }
+            None => false,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_at(Utc::now())
+    }
+}
+
+/// Saves cursors under a key identifying the listing they belong to.
+pub trait CursorStore {
+    fn load(&self, key: &str) -> io::Result<Option<StoredCursor>>;
+
+    fn save(&self, key: &str, cursor: &StoredCursor) -> io::Result<()>;
+
+    fn remove(&self, key: &str) -> io::Result<()>;
+
+    /// Loads the cursor saved under `key` unless it expired, in which case it is removed.
+    fn load_unexpired(&self, key: &str) -> io::Result<Option<StoredCursor>> {
+        match self.load(key)? {
+            Some(ref cursor) if cursor.is_expired() => {
+                info!("[CursorStore] [key = {}] cursor expired, starting over", key);
+                self.remove(key)?;
+                Ok(None)
+            }
+            cursor => Ok(cursor),
+        }
+    }
+}
+
+/// Keeps cursors in memory. Clones share the same cursors.
+#[derive(Debug,Clone,Default)]
+pub struct MemoryCursorStore {
+    cursors: Rc<RefCell<HashMap<String, StoredCursor>>>,
+}
+
+impl MemoryCursorStore {
+    pub fn new() -> MemoryCursorStore {
+        MemoryCursorStore::default()
+    }
+}
+
+impl CursorStore for MemoryCursorStore {
+    fn load(&self, key: &str) -> io::Result<Option<StoredCursor>> {
+        Ok(self.cursors.borrow().get(key).cloned())
+    }
+
+    fn save(&self, key: &str, cursor: &StoredCursor) -> io::Result<()> {
+        self.cursors.borrow_mut().insert(key.to_owned(), cursor.clone());
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> io::Result<()> {
+        self.cursors.borrow_mut().remove(key);
+        Ok(())
+    }
+}
+
+/// Keeps cursors in a JSON file, which is replaced atomically on every save so that a crash
+/// never leaves it half written.
+#[derive(Debug,Clone)]
+pub struct FileCursorStore {
+    path: PathBuf,
+}
+
+impl FileCursorStore {
+    pub fn new<P: AsRef<Path>>(path: P) -> FileCursorStore {
+        FileCursorStore { path: path.as_ref().to_owned() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn read_all(&self) -> io::Result<HashMap<String, StoredCursor>> {
+        match File::open(&self.path) {
+            Ok(file) => {
+                serde_json::from_reader(file)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            }
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(HashMap::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn write_all(&self, cursors: &HashMap<String, StoredCursor>) -> io::Result<()> {
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        {
+            let file = File::create(&tmp_path)?;
+            serde_json::to_writer(&file, cursors)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            file.sync_all()?;
+        }
+        fs::rename(&tmp_path, &self.path)
+    }
+}
+
+impl CursorStore for FileCursorStore {
+    fn load(&self, key: &str) -> io::Result<Option<StoredCursor>> {
+        Ok(self.read_all()?.remove(key))
+    }
+
+    fn save(&self, key: &str, cursor: &StoredCursor) -> io::Result<()> {
+        let mut cursors = self.read_all()?;
+        cursors.insert(key.to_owned(), cursor.clone());
+        self.write_all(&cursors)
+    }
+
+    fn remove(&self, key: &str) -> io::Result<()> {
+        let mut cursors = self.read_all()?;
+        if cursors.remove(key).is_some() {
+            self.write_all(&cursors)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+
+    use std::env;
+    use std::fs;
+    use std::process;
+
+    use super::*;
+
+    #[test]
+    fn test_stored_cursor_expiration() {
+        let now = "2018-01-01T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert!(StoredCursor::new("c", Some("2018-01-01T11:59:59Z")).is_expired_at(now));
+        assert!(!StoredCursor::new("c", Some("2018-01-01T12:00:01Z")).is_expired_at(now));
+        assert!(!StoredCursor::new("c", None).is_expired_at(now));
+    }
+
+    #[test]
+    fn test_file_cursor_store() {
+        let path = env::temp_dir().join(format!("dropbox_rs_cursors_{}.json", process::id()));
+        let store = FileCursorStore::new(&path);
+        let cursor = StoredCursor::new("c1", None);
+        let expired = StoredCursor::new("c2", Some("2000-01-01T00:00:00Z"));
+
+        assert_eq!(store.load("docs").unwrap(), None);
+        store.save("docs", &cursor).unwrap();
+        store.save("old", &expired).unwrap();
+
+        let reopened = FileCursorStore::new(&path);
+        assert_eq!(reopened.load("docs").unwrap(), Some(cursor));
+        assert_eq!(reopened.load_unexpired("old").unwrap(), None);
+        assert_eq!(reopened.load("old").unwrap(), None);
+
+        reopened.remove("docs").unwrap();
+        assert_eq!(store.load("docs").unwrap(), None);
+        fs::remove_file(&path).unwrap();
+    }
+}
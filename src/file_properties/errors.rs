@@ -0,0 +1,196 @@
+use http::errors::APIError;
+
+error_chain!{
+    links {
+        Http(::http::errors::Error, ::http::errors::ErrorKind);
+    }
+    foreign_links {
+        Url(::reqwest::UrlError);
+        Reqwest(::reqwest::Error);
+        Utf8(::std::string::FromUtf8Error);
+        Io(::std::io::Error);
+        Json(::serde_json::Error);
+        UrlEncodedSer(::serde_urlencoded::ser::Error);
+    }
+    errors {
+        TemplateErr(error: APIError<TemplateError>) {
+            description("TemplateError"),
+            display("{:?}", error)
+        }
+        ModifyTemplateErr(error: APIError<ModifyTemplateError>) {
+            description("ModifyTemplateError"),
+            display("{:?}", error)
+        }
+        AddPropertiesErr(error: APIError<AddPropertiesError>) {
+            description("AddPropertiesError"),
+            display("{:?}", error)
+        }
+        InvalidPropertyGroupErr(error: APIError<InvalidPropertyGroupError>) {
+            description("InvalidPropertyGroupError"),
+            display("{:?}", error)
+        }
+        UpdatePropertiesErr(error: APIError<UpdatePropertiesError>) {
+            description("UpdatePropertiesError"),
+            display("{:?}", error)
+        }
+        RemovePropertiesErr(error: APIError<RemovePropertiesError>) {
+            description("RemovePropertiesError"),
+            display("{:?}", error)
+        }
+        PropertiesSearchErr(error: APIError<PropertiesSearchError>) {
+            description("PropertiesSearchError"),
+            display("{:?}", error)
+        }
+        PropertiesSearchContinueErr(error: APIError<PropertiesSearchContinueError>) {
+            description("PropertiesSearchContinueError"),
+            display("{:?}", error)
+        }
+        MissingPropertyField(template_id: String, name: String) {
+            description("A property group is missing a field"),
+            display("Property group of template {} has no field {}", template_id, name)
+        }
+    }
+}
+
+impl From<APIError<TemplateError>> for ErrorKind {
+    fn from(error: APIError<TemplateError>) -> Self {
+        ErrorKind::TemplateErr(error)
+    }
+}
+
+impl From<APIError<ModifyTemplateError>> for ErrorKind {
+    fn from(error: APIError<ModifyTemplateError>) -> Self {
+        ErrorKind::ModifyTemplateErr(error)
+    }
+}
+
+impl From<APIError<AddPropertiesError>> for ErrorKind {
+    fn from(error: APIError<AddPropertiesError>) -> Self {
+        ErrorKind::AddPropertiesErr(error)
+    }
+}
+
+impl From<APIError<InvalidPropertyGroupError>> for ErrorKind {
+    fn from(error: APIError<InvalidPropertyGroupError>) -> Self {
+        ErrorKind::InvalidPropertyGroupErr(error)
+    }
+}
+
+impl From<APIError<UpdatePropertiesError>> for ErrorKind {
+    fn from(error: APIError<UpdatePropertiesError>) -> Self {
+        ErrorKind::UpdatePropertiesErr(error)
+    }
+}
+
+impl From<APIError<RemovePropertiesError>> for ErrorKind {
+    fn from(error: APIError<RemovePropertiesError>) -> Self {
+        ErrorKind::RemovePropertiesErr(error)
+    }
+}
+
+impl From<APIError<PropertiesSearchError>> for ErrorKind {
+    fn from(error: APIError<PropertiesSearchError>) -> Self {
+        ErrorKind::PropertiesSearchErr(error)
+    }
+}
+
+impl From<APIError<PropertiesSearchContinueError>> for ErrorKind {
+    fn from(error: APIError<PropertiesSearchContinueError>) -> Self {
+        ErrorKind::PropertiesSearchContinueErr(error)
+    }
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum TemplateError {
+    TemplateNotFound { template_not_found: String },
+    RestrictedContent,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum ModifyTemplateError {
+    TemplateNotFound { template_not_found: String },
+    RestrictedContent,
+    ConflictingPropertyNames,
+    TooManyProperties,
+    TooManyTemplates,
+    TemplateAttributeTooLarge,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum LookupError {
+    MalformedPath { malformed_path: String },
+    NotFound,
+    NotFile,
+    NotFolder,
+    RestrictedContent,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum LookUpPropertiesError {
+    PropertyGroupNotFound,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum InvalidPropertyGroupError {
+    TemplateNotFound { template_not_found: String },
+    RestrictedContent,
+    Path { path: LookupError },
+    UnsupportedFolder,
+    PropertyFieldTooLarge,
+    DoesNotFitTemplate,
+    DuplicatePropertyGroups,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum AddPropertiesError {
+    TemplateNotFound { template_not_found: String },
+    RestrictedContent,
+    Path { path: LookupError },
+    UnsupportedFolder,
+    PropertyFieldTooLarge,
+    DoesNotFitTemplate,
+    DuplicatePropertyGroups,
+    PropertyGroupAlreadyExists,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum UpdatePropertiesError {
+    TemplateNotFound { template_not_found: String },
+    RestrictedContent,
+    Path { path: LookupError },
+    UnsupportedFolder,
+    PropertyFieldTooLarge,
+    DoesNotFitTemplate,
+    DuplicatePropertyGroups,
+    PropertyGroupLookup { property_group_lookup: LookUpPropertiesError },
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum RemovePropertiesError {
+    TemplateNotFound { template_not_found: String },
+    RestrictedContent,
+    Path { path: LookupError },
+    UnsupportedFolder,
+    PropertyGroupLookup { property_group_lookup: LookUpPropertiesError },
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum PropertiesSearchError {
+    PropertyGroupLookup { property_group_lookup: LookUpPropertiesError },
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum PropertiesSearchContinueError {
+    /// The cursor is no longer valid and the search must be started again.
+    Reset,
+}
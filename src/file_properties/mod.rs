@@ -0,0 +1,248 @@
+//! [Dropbox File Properties Documentation](https://www.dropbox.com/developers/documentation/http/documentation#file_properties)
+//!
+//! This namespace contains helpers for property and template metadata endpoints. Templates
+//! describe a set of string fields which can then be attached to files as property groups.
+//!
+
+pub mod errors;
+pub mod properties;
+pub mod templates;
+
+use reqwest::Url;
+
+use std::rc::Rc;
+
+use self::errors::*;
+use http::{Response, ResponseWithErr};
+use http::RPCClient;
+
+use self::properties::{PropertyGroup, PropertyGroupUpdate, AddPropertiesArg,
+                       OverwritePropertyGroupArg, UpdatePropertiesArg, RemovePropertiesArg,
+                       PropertiesSearchRequestBuilder, PropertiesSearchResult};
+use self::templates::{PropertyGroupTemplate, PropertyFieldTemplate, AddTemplateResult,
+                      ListTemplateResult, UpdateTemplateRequestBuilder, TemplateOwner};
+
+static BASE_URL: &'static str = "https://api.dropboxapi.com/2/file_properties/";
+
+/// A client to interface with the endpoints in the File Properties namespace of the Dropbox APIs
+#[derive(Debug,Clone)]
+pub struct FileProperties {
+    access_token: Rc<String>,
+}
+
+impl ::http::HasAccessToken for FileProperties {
+    fn access_token(&self) -> &str {
+        self.access_token.as_str()
+    }
+}
+
+impl FileProperties {
+    pub fn new(access_token: Rc<String>) -> FileProperties {
+        FileProperties { access_token: Rc::clone(&access_token) }
+    }
+
+    /// Add property groups to a Dropbox file. See templates/add_for_user or
+    /// templates/add_for_team to create new templates.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#file_properties-properties-add)
+    pub fn properties_add(&self,
+                          path: &str,
+                          property_groups: &[PropertyGroup])
+                          -> Result<Response<()>> {
+        let url = Url::parse(BASE_URL)?.join("properties/add")?;
+        let resp_w_err = self.rpc_request(url,
+                         &AddPropertiesArg {
+                             path: path.to_owned(),
+                             property_groups: property_groups.to_vec(),
+                         })?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::AddPropertiesErr(e).into()),
+        }
+    }
+
+    /// Overwrite property groups associated with a file. This endpoint should be used instead of
+    /// properties/update when property groups are being updated via a "snapshot" instead of via a
+    /// "delta".
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#file_properties-properties-overwrite)
+    pub fn properties_overwrite(&self,
+                                path: &str,
+                                property_groups: &[PropertyGroup])
+                                -> Result<Response<()>> {
+        let url = Url::parse(BASE_URL)?.join("properties/overwrite")?;
+        let resp_w_err = self.rpc_request(url,
+                         &OverwritePropertyGroupArg {
+                             path: path.to_owned(),
+                             property_groups: property_groups.to_vec(),
+                         })?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::InvalidPropertyGroupErr(e).into()),
+        }
+    }
+
+    /// Add, update or remove properties associated with the supplied file and templates. This
+    /// endpoint should be used instead of properties/overwrite when property groups are being
+    /// updated via a "delta" instead of via a "snapshot".
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#file_properties-properties-update)
+    pub fn properties_update(&self,
+                             path: &str,
+                             update_property_groups: &[PropertyGroupUpdate])
+                             -> Result<Response<()>> {
+        let url = Url::parse(BASE_URL)?.join("properties/update")?;
+        let resp_w_err = self.rpc_request(url,
+                         &UpdatePropertiesArg {
+                             path: path.to_owned(),
+                             update_property_groups: update_property_groups.to_vec(),
+                         })?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::UpdatePropertiesErr(e).into()),
+        }
+    }
+
+    /// Permanently removes the specified property groups from the file.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#file_properties-properties-remove)
+    pub fn properties_remove(&self,
+                             path: &str,
+                             property_template_ids: &[&str])
+                             -> Result<Response<()>> {
+        let url = Url::parse(BASE_URL)?.join("properties/remove")?;
+        let resp_w_err = self.rpc_request(url,
+                         &RemovePropertiesArg {
+                             path: path.to_owned(),
+                             property_template_ids: property_template_ids.iter()
+                                 .map(|x| (*x).to_owned())
+                                 .collect(),
+                         })?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::RemovePropertiesErr(e).into()),
+        }
+    }
+
+    /// Search across property templates for particular property field values.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#file_properties-properties-search)
+    pub fn properties_search(&self) -> PropertiesSearchRequestBuilder<FileProperties> {
+        PropertiesSearchRequestBuilder::new(self)
+    }
+
+    /// Once a cursor has been retrieved from properties/search, use this to paginate through all
+    /// search results.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#file_properties-properties-search-continue)
+    pub fn properties_search_continue(&self,
+                                      cursor: &str)
+                                      -> Result<Response<PropertiesSearchResult>> {
+        properties::search_continue(self, cursor)
+    }
+
+    /// Add a template associated with a user. A template can be used to attach properties to
+    /// files and folders.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#file_properties-templates-add_for_user)
+    pub fn templates_add_for_user(&self,
+                                  name: &str,
+                                  description: &str,
+                                  fields: &[PropertyFieldTemplate])
+                                  -> Result<Response<AddTemplateResult>> {
+        self.templates_add(TemplateOwner::User, name, description, fields)
+    }
+
+    /// Get the schema for a specified template.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#file_properties-templates-get_for_user)
+    pub fn templates_get_for_user(&self,
+                                  template_id: &str)
+                                  -> Result<Response<PropertyGroupTemplate>> {
+        templates::get_template(self, TemplateOwner::User, template_id)
+    }
+
+    /// Get the template identifiers for a user.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#file_properties-templates-list_for_user)
+    pub fn templates_list_for_user(&self) -> Result<Response<ListTemplateResult>> {
+        templates::list_templates(self, TemplateOwner::User)
+    }
+
+    /// Update a template associated with a user. This route can update the template name, the
+    /// template description and add optional properties to templates.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#file_properties-templates-update_for_user)
+    pub fn templates_update_for_user(&self,
+                                     template_id: &str)
+                                     -> UpdateTemplateRequestBuilder<FileProperties> {
+        UpdateTemplateRequestBuilder::new(self, TemplateOwner::User, template_id)
+    }
+
+    /// Permanently removes the specified template created from templates/add_for_user. All
+    /// properties associated with the template will also be removed. This action cannot be undone.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#file_properties-templates-remove_for_user)
+    pub fn templates_remove_for_user(&self, template_id: &str) -> Result<Response<()>> {
+        templates::remove_template(self, TemplateOwner::User, template_id)
+    }
+
+    /// Add a template associated with a team. Needs a team access token.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#file_properties-templates-add_for_team)
+    pub fn templates_add_for_team(&self,
+                                  name: &str,
+                                  description: &str,
+                                  fields: &[PropertyFieldTemplate])
+                                  -> Result<Response<AddTemplateResult>> {
+        self.templates_add(TemplateOwner::Team, name, description, fields)
+    }
+
+    /// Get the schema for a specified template. Needs a team access token.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#file_properties-templates-get_for_team)
+    pub fn templates_get_for_team(&self,
+                                  template_id: &str)
+                                  -> Result<Response<PropertyGroupTemplate>> {
+        templates::get_template(self, TemplateOwner::Team, template_id)
+    }
+
+    /// Get the template identifiers for a team. Needs a team access token.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#file_properties-templates-list_for_team)
+    pub fn templates_list_for_team(&self) -> Result<Response<ListTemplateResult>> {
+        templates::list_templates(self, TemplateOwner::Team)
+    }
+
+    /// Update a template associated with a team. Needs a team access token.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#file_properties-templates-update_for_team)
+    pub fn templates_update_for_team(&self,
+                                     template_id: &str)
+                                     -> UpdateTemplateRequestBuilder<FileProperties> {
+        UpdateTemplateRequestBuilder::new(self, TemplateOwner::Team, template_id)
+    }
+
+    /// Permanently removes the specified template created from templates/add_for_team. Needs a
+    /// team access token.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#file_properties-templates-remove_for_team)
+    pub fn templates_remove_for_team(&self, template_id: &str) -> Result<Response<()>> {
+        templates::remove_template(self, TemplateOwner::Team, template_id)
+    }
+
+    fn templates_add(&self,
+                     owner: TemplateOwner,
+                     name: &str,
+                     description: &str,
+                     fields: &[PropertyFieldTemplate])
+                     -> Result<Response<AddTemplateResult>> {
+        templates::add_template(self,
+                                owner,
+                                &PropertyGroupTemplate {
+                                    name: name.to_owned(),
+                                    description: description.to_owned(),
+                                    fields: fields.to_vec(),
+                                })
+    }
+}
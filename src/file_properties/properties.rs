@@ -0,0 +1,298 @@
+use reqwest::Url;
+
+use file_properties::errors::*;
+use file_properties::errors::ErrorKind::{MissingPropertyField, PropertiesSearchContinueErr};
+use http::{Response, ResponseWithErr};
+use http::RPCClient;
+use http::pagination::{Page, Paginated, PaginatedIter};
+
+/**
+ * properties
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct PropertyField {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct PropertyGroup {
+    pub template_id: String,
+    pub fields: Vec<PropertyField>,
+}
+
+impl PropertyGroup {
+    pub fn new(template_id: &str) -> PropertyGroup {
+        PropertyGroup {
+            template_id: template_id.to_owned(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// Adds a field to the group, replacing the value of any field with the same name.
+    pub fn with_field(mut self, name: &str, value: &str) -> PropertyGroup {
+        self.fields.retain(|field| field.name != name);
+        self.fields.push(PropertyField {
+            name: name.to_owned(),
+            value: value.to_owned(),
+        });
+        self
+    }
+
+    pub fn field(&self, name: &str) -> Option<&str> {
+        self.fields.iter().find(|field| field.name == name).map(|field| field.value.as_str())
+    }
+
+    /// Like `field` but fails with `ErrorKind::MissingPropertyField` if there's no such field.
+    pub fn required_field(&self, name: &str) -> Result<&str> {
+        self.field(name)
+            .ok_or_else(|| MissingPropertyField(self.template_id.clone(), name.to_owned()).into())
+    }
+}
+
+/// Maps a struct to the property group of a template, so that it can be stored on files and read
+/// back from them.
+///
+/// ```
+/// use dropbox_rs::file_properties::errors::Result;
+/// use dropbox_rs::file_properties::properties::{PropertyGroup, ToPropertyGroup};
+///
+/// struct DocumentControl {
+///     status: String,
+///     owner: String,
+/// }
+///
+/// impl ToPropertyGroup for DocumentControl {
+///     fn to_property_group(&self, template_id: &str) -> PropertyGroup {
+///         PropertyGroup::new(template_id)
+///             .with_field("status", &self.status)
+///             .with_field("owner", &self.owner)
+///     }
+///
+///     fn from_property_group(group: &PropertyGroup) -> Result<DocumentControl> {
+///         Ok(DocumentControl {
+///             status: group.required_field("status")?.to_owned(),
+///             owner: group.required_field("owner")?.to_owned(),
+///         })
+///     }
+/// }
+/// ```
+pub trait ToPropertyGroup: Sized {
+    fn to_property_group(&self, template_id: &str) -> PropertyGroup;
+
+    fn from_property_group(group: &PropertyGroup) -> Result<Self>;
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct AddPropertiesArg {
+    pub path: String,
+    pub property_groups: Vec<PropertyGroup>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct OverwritePropertyGroupArg {
+    pub path: String,
+    pub property_groups: Vec<PropertyGroup>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct PropertyGroupUpdate {
+    pub template_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub add_or_update_fields: Option<Vec<PropertyField>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remove_fields: Option<Vec<String>>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct UpdatePropertiesArg {
+    pub path: String,
+    pub update_property_groups: Vec<PropertyGroupUpdate>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct RemovePropertiesArg {
+    pub path: String,
+    pub property_template_ids: Vec<String>,
+}
+
+/**
+ * properties/search
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum PropertiesSearchMode {
+    FieldName { field_name: String },
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum LogicalOperator {
+    OrOperator,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct PropertiesSearchQuery {
+    pub query: String,
+    pub mode: PropertiesSearchMode,
+    pub logical_operator: LogicalOperator,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum TemplateFilter {
+    FilterNone,
+    FilterSome { filter_some: Vec<String> },
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct PropertiesSearchArg {
+    pub queries: Vec<PropertiesSearchQuery>,
+    pub template_filter: TemplateFilter,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct PropertiesSearchContinueArg {
+    pub cursor: String,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct PropertiesSearchMatch {
+    pub id: String,
+    pub path: String,
+    pub is_deleted: bool,
+    pub property_groups: Vec<PropertyGroup>,
+}
+
+impl PropertiesSearchMatch {
+    pub fn property_group(&self, template_id: &str) -> Option<&PropertyGroup> {
+        self.property_groups.iter().find(|group| group.template_id == template_id)
+    }
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct PropertiesSearchResult {
+    pub matches: Vec<PropertiesSearchMatch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+pub struct PropertiesSearchRequestBuilder<T> {
+    client: T,
+    arg: PropertiesSearchArg,
+}
+
+impl<T> PropertiesSearchRequestBuilder<T>
+    where T: RPCClient + Clone
+{
+    pub fn new(client: &T) -> PropertiesSearchRequestBuilder<T> {
+        PropertiesSearchRequestBuilder {
+            client: client.clone(),
+            arg: PropertiesSearchArg {
+                queries: Vec::new(),
+                template_filter: TemplateFilter::FilterNone,
+            },
+        }
+    }
+
+    /// Matches the files having a property named `field_name` whose value is `query`. Files
+    /// matching any of the queries are returned.
+    pub fn add_query(&mut self,
+                     field_name: &str,
+                     query: &str)
+                     -> &mut PropertiesSearchRequestBuilder<T> {
+        self.arg.queries.push(PropertiesSearchQuery {
+            query: query.to_owned(),
+            mode: PropertiesSearchMode::FieldName { field_name: field_name.to_owned() },
+            logical_operator: LogicalOperator::OrOperator,
+        });
+        self
+    }
+
+    /// Only returns the property groups of the given templates.
+    pub fn template_ids(&mut self, template_ids: &[&str]) -> &mut PropertiesSearchRequestBuilder<T> {
+        self.arg.template_filter = TemplateFilter::FilterSome {
+            filter_some: template_ids.iter().map(|x| (*x).to_owned()).collect(),
+        };
+        self
+    }
+
+    /// Fetches the first page of matches. Use `FileProperties::properties_search_continue` with
+    /// the returned cursor to fetch the following pages or `iter` to follow them automatically.
+    pub fn send(&self) -> Result<Response<PropertiesSearchResult>> {
+        search(&self.client, &self.arg)
+    }
+
+    /// Returns an iterator over every match, fetching the following pages as needed.
+    pub fn iter(&self) -> PaginatedIter<PropertiesSearchPages<T>> {
+        PropertiesSearchPages {
+                client: self.client.clone(),
+                arg: self.arg.clone(),
+            }
+            .iter_all()
+    }
+}
+
+/// The pages of matches returned by properties/search and properties/search/continue.
+pub struct PropertiesSearchPages<T> {
+    pub client: T,
+    pub arg: PropertiesSearchArg,
+}
+
+impl<T: RPCClient> Paginated for PropertiesSearchPages<T> {
+    type Item = PropertiesSearchMatch;
+    type Error = Error;
+
+    fn first_page(&self) -> Result<Page<PropertiesSearchMatch>> {
+        Ok(search_page(search(&self.client, &self.arg)?.body))
+    }
+
+    fn next_page(&self, cursor: &str) -> Result<Page<PropertiesSearchMatch>> {
+        Ok(search_page(search_continue(&self.client, cursor)?.body))
+    }
+
+    fn should_restart(&self, error: &Error) -> bool {
+        match *error.kind() {
+            PropertiesSearchContinueErr(ref e) => e.error == PropertiesSearchContinueError::Reset,
+            _ => false,
+        }
+    }
+
+    fn item_key(&self, search_match: &PropertiesSearchMatch) -> String {
+        search_match.id.clone()
+    }
+}
+
+fn search_page(result: PropertiesSearchResult) -> Page<PropertiesSearchMatch> {
+    Page {
+        items: result.matches,
+        has_more: result.cursor.is_some(),
+        cursor: result.cursor.unwrap_or_default(),
+        cursor_expiration: None,
+    }
+}
+
+pub fn search<T: RPCClient>(client: &T,
+                            arg: &PropertiesSearchArg)
+                            -> Result<Response<PropertiesSearchResult>> {
+    let url = Url::parse(super::BASE_URL)?.join("properties/search")?;
+    let resp_w_err = client.rpc_request(url, arg)?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::PropertiesSearchErr(e).into()),
+    }
+}
+
+pub fn search_continue<T: RPCClient>(client: &T,
+                                     cursor: &str)
+                                     -> Result<Response<PropertiesSearchResult>> {
+    let url = Url::parse(super::BASE_URL)?.join("properties/search/continue")?;
+    let resp_w_err = client.rpc_request(url,
+                                        &PropertiesSearchContinueArg {
+                                            cursor: cursor.to_owned(),
+                                        })?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::PropertiesSearchContinueErr(e).into()),
+    }
+}
@@ -0,0 +1,197 @@
+use reqwest::Url;
+
+use file_properties::errors::*;
+use http::{Response, ResponseWithErr};
+use http::RPCClient;
+
+/**
+ * templates
+ **/
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum PropertyType {
+    String,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct PropertyFieldTemplate {
+    pub name: String,
+    pub description: String,
+    #[serde(rename = "type")]
+    pub property_type: PropertyType,
+}
+
+impl PropertyFieldTemplate {
+    /// A field holding a string, the only type of field supported by Dropbox.
+    pub fn string(name: &str, description: &str) -> PropertyFieldTemplate {
+        PropertyFieldTemplate {
+            name: name.to_owned(),
+            description: description.to_owned(),
+            property_type: PropertyType::String,
+        }
+    }
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct PropertyGroupTemplate {
+    pub name: String,
+    pub description: String,
+    pub fields: Vec<PropertyFieldTemplate>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct AddTemplateResult {
+    pub template_id: String,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct GetTemplateArg {
+    pub template_id: String,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ListTemplateResult {
+    pub template_ids: Vec<String>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct UpdateTemplateArg {
+    pub template_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub add_fields: Option<Vec<PropertyFieldTemplate>>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct UpdateTemplateResult {
+    pub template_id: String,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct RemoveTemplateArg {
+    pub template_id: String,
+}
+
+/// Whether templates belong to the user of the access token or to their team. Team templates
+/// need a team access token.
+#[derive(PartialEq,Eq,Debug,Copy,Clone)]
+pub enum TemplateOwner {
+    User,
+    Team,
+}
+
+impl TemplateOwner {
+    fn endpoint(&self, action: &str) -> String {
+        match *self {
+            TemplateOwner::User => format!("templates/{}_for_user", action),
+            TemplateOwner::Team => format!("templates/{}_for_team", action),
+        }
+    }
+}
+
+pub fn add_template<T: RPCClient>(client: &T,
+                                  owner: TemplateOwner,
+                                  template: &PropertyGroupTemplate)
+                                  -> Result<Response<AddTemplateResult>> {
+    let url = Url::parse(super::BASE_URL)?.join(&owner.endpoint("add"))?;
+    let resp_w_err = client.rpc_request(url, template)?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::ModifyTemplateErr(e).into()),
+    }
+}
+
+pub fn get_template<T: RPCClient>(client: &T,
+                                  owner: TemplateOwner,
+                                  template_id: &str)
+                                  -> Result<Response<PropertyGroupTemplate>> {
+    let url = Url::parse(super::BASE_URL)?.join(&owner.endpoint("get"))?;
+    let resp_w_err = client.rpc_request(url,
+                                        &GetTemplateArg { template_id: template_id.to_owned() })?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::TemplateErr(e).into()),
+    }
+}
+
+pub fn list_templates<T: RPCClient>(client: &T,
+                                    owner: TemplateOwner)
+                                    -> Result<Response<ListTemplateResult>> {
+    let url = Url::parse(super::BASE_URL)?.join(&owner.endpoint("list"))?;
+    let resp_w_err = client.rpc_request(url, ())?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::TemplateErr(e).into()),
+    }
+}
+
+pub fn remove_template<T: RPCClient>(client: &T,
+                                     owner: TemplateOwner,
+                                     template_id: &str)
+                                     -> Result<Response<()>> {
+    let url = Url::parse(super::BASE_URL)?.join(&owner.endpoint("remove"))?;
+    let resp_w_err = client.rpc_request(url,
+                                        &RemoveTemplateArg {
+                                            template_id: template_id.to_owned(),
+                                        })?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::TemplateErr(e).into()),
+    }
+}
+
+pub struct UpdateTemplateRequestBuilder<T> {
+    client: T,
+    owner: TemplateOwner,
+    arg: UpdateTemplateArg,
+}
+
+impl<T> UpdateTemplateRequestBuilder<T>
+    where T: RPCClient + Clone
+{
+    pub fn new(client: &T,
+               owner: TemplateOwner,
+               template_id: &str)
+               -> UpdateTemplateRequestBuilder<T> {
+        UpdateTemplateRequestBuilder {
+            client: client.clone(),
+            owner: owner,
+            arg: UpdateTemplateArg {
+                template_id: template_id.to_owned(),
+                name: None,
+                description: None,
+                add_fields: None,
+            },
+        }
+    }
+
+    pub fn name(&mut self, name: &str) -> &mut UpdateTemplateRequestBuilder<T> {
+        self.arg.name = Some(name.to_owned());
+        self
+    }
+
+    pub fn description(&mut self, description: &str) -> &mut UpdateTemplateRequestBuilder<T> {
+        self.arg.description = Some(description.to_owned());
+        self
+    }
+
+    /// Adds a field to the template. Existing fields can't be changed or removed.
+    pub fn add_field(&mut self,
+                     field: PropertyFieldTemplate)
+                     -> &mut UpdateTemplateRequestBuilder<T> {
+        self.arg.add_fields.get_or_insert_with(Vec::new).push(field);
+        self
+    }
+
+    pub fn send(&self) -> Result<Response<UpdateTemplateResult>> {
+        let url = Url::parse(super::BASE_URL)?.join(&self.owner.endpoint("update"))?;
+        let resp_w_err = self.client.rpc_request(url, &self.arg)?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::ModifyTemplateErr(e).into()),
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use http::errors::APIError;
+
+error_chain!{
+    links {
+        Http(::http::errors::Error, ::http::errors::ErrorKind);
+    }
+    foreign_links {
+        Url(::reqwest::UrlError);
+        Reqwest(::reqwest::Error);
+        Utf8(::std::string::FromUtf8Error);
+        Io(::std::io::Error);
+        Json(::serde_json::Error);
+        UrlEncodedSer(::serde_urlencoded::ser::Error);
+    }
+    errors {
+        GeneralFileRequestsErr(error: APIError<GeneralFileRequestsError>) {
+            description("GeneralFileRequestsError"),
+            display("{:?}", error)
+        }
+        FileRequestErr(error: APIError<FileRequestError>) {
+            description("FileRequestError"),
+            display("{:?}", error)
+        }
+        CreateFileRequestErr(error: APIError<CreateFileRequestError>) {
+            description("CreateFileRequestError"),
+            display("{:?}", error)
+        }
+        DeleteFileRequestErr(error: APIError<DeleteFileRequestError>) {
+            description("DeleteFileRequestError"),
+            display("{:?}", error)
+        }
+        ListFileRequestsContinueErr(error: APIError<ListFileRequestsContinueError>) {
+            description("ListFileRequestsContinueError"),
+            display("{:?}", error)
+        }
+    }
+}
+
+impl From<APIError<GeneralFileRequestsError>> for ErrorKind {
+    fn from(error: APIError<GeneralFileRequestsError>) -> Self {
+        ErrorKind::GeneralFileRequestsErr(error)
+    }
+}
+
+impl From<APIError<FileRequestError>> for ErrorKind {
+    fn from(error: APIError<FileRequestError>) -> Self {
+        ErrorKind::FileRequestErr(error)
+    }
+}
+
+impl From<APIError<CreateFileRequestError>> for ErrorKind {
+    fn from(error: APIError<CreateFileRequestError>) -> Self {
+        ErrorKind::CreateFileRequestErr(error)
+    }
+}
+
+impl From<APIError<DeleteFileRequestError>> for ErrorKind {
+    fn from(error: APIError<DeleteFileRequestError>) -> Self {
+        ErrorKind::DeleteFileRequestErr(error)
+    }
+}
+
+impl From<APIError<ListFileRequestsContinueError>> for ErrorKind {
+    fn from(error: APIError<ListFileRequestsContinueError>) -> Self {
+        ErrorKind::ListFileRequestsContinueErr(error)
+    }
+}
+
+/// Returned by file_requests/count and file_requests/list_v2.
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum GeneralFileRequestsError {
+    /// This user's Dropbox Business team doesn't allow file requests.
+    DisabledForTeam,
+}
+
+/// Returned by file_requests/get, file_requests/update and file_requests/delete_all_closed.
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum FileRequestError {
+    DisabledForTeam,
+    NotFound,
+    NotAFolder,
+    AppLacksAccess,
+    NoPermission,
+    EmailUnverified,
+    ValidationError,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum CreateFileRequestError {
+    DisabledForTeam,
+    NotFound,
+    NotAFolder,
+    AppLacksAccess,
+    NoPermission,
+    EmailUnverified,
+    ValidationError,
+    /// File requests are not available on the specified folder.
+    InvalidLocation,
+    /// The user has reached the rate limit for creating file requests.
+    RateLimit,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum DeleteFileRequestError {
+    DisabledForTeam,
+    NotFound,
+    NotAFolder,
+    AppLacksAccess,
+    NoPermission,
+    EmailUnverified,
+    ValidationError,
+    /// One or more file requests are still open.
+    FileRequestOpen,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum ListFileRequestsContinueError {
+    DisabledForTeam,
+    InvalidCursor,
+}
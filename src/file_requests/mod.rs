@@ -0,0 +1,492 @@
+//! [Dropbox File Requests Documentation](https://www.dropbox.com/developers/documentation/http/documentation#file_requests)
+//!
+//! This namespace contains endpoints and data types for file request operations.
+//!
+
+pub mod errors;
+
+use serde::{Serialize, Serializer};
+use serde::ser::SerializeMap;
+use reqwest::Url;
+
+use std::rc::Rc;
+
+use self::errors::*;
+use http::{Response, ResponseWithErr};
+use http::RPCClient;
+use http::pagination::{Page, Paginated, PaginatedIter};
+
+static BASE_URL: &'static str = "https://api.dropboxapi.com/2/file_requests/";
+
+/// The largest page size accepted by file_requests/list_v2.
+const MAX_PAGE_LIMIT: u64 = 1000;
+
+/// A client to interface with the endpoints in the File Requests namespace of the Dropbox APIs
+#[derive(Debug,Clone)]
+pub struct FileRequests {
+    access_token: Rc<String>,
+}
+
+impl ::http::HasAccessToken for FileRequests {
+    fn access_token(&self) -> &str {
+        self.access_token.as_str()
+    }
+}
+
+impl FileRequests {
+    pub fn new(access_token: Rc<String>) -> FileRequests {
+        FileRequests { access_token: Rc::clone(&access_token) }
+    }
+
+    /// Returns the total number of file requests owned by this user. Includes both open and
+    /// closed file requests.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#file_requests-count)
+    pub fn count(&self) -> Result<Response<CountFileRequestsResult>> {
+        let url = Url::parse(BASE_URL)?.join("count")?;
+        let resp_w_err = self.rpc_request(url, ())?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::GeneralFileRequestsErr(e).into()),
+        }
+    }
+
+    /// Creates a file request for this user, collecting uploads into the folder at `destination`.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#file_requests-create)
+    pub fn create(&self,
+                  title: &str,
+                  destination: &str)
+                  -> CreateFileRequestRequestBuilder<FileRequests> {
+        CreateFileRequestRequestBuilder::new(self, title, destination)
+    }
+
+    /// Delete a batch of closed file requests.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#file_requests-delete)
+    pub fn delete(&self, ids: &[&str]) -> Result<Response<DeleteFileRequestsResult>> {
+        let url = Url::parse(BASE_URL)?.join("delete")?;
+        let resp_w_err = self.rpc_request(url,
+                         &DeleteFileRequestArgs {
+                             ids: ids.iter().map(|x| (*x).to_owned()).collect(),
+                         })?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::DeleteFileRequestErr(e).into()),
+        }
+    }
+
+    /// Delete all closed file requests owned by this user.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#file_requests-delete_all_closed)
+    pub fn delete_all_closed(&self) -> Result<Response<DeleteFileRequestsResult>> {
+        let url = Url::parse(BASE_URL)?.join("delete_all_closed")?;
+        let resp_w_err = self.rpc_request(url, ())?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::FileRequestErr(e).into()),
+        }
+    }
+
+    /// Returns the specified file request.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#file_requests-get)
+    pub fn get(&self, id: &str) -> Result<Response<FileRequest>> {
+        let url = Url::parse(BASE_URL)?.join("get")?;
+        let resp_w_err = self.rpc_request(url, &GetFileRequestArgs { id: id.to_owned() })?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::FileRequestErr(e).into()),
+        }
+    }
+
+    /// Returns a list of file requests owned by this user. For apps with the app folder
+    /// permission, this will only return file requests with destinations in the app folder.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#file_requests-list_v2)
+    pub fn list(&self, limit: u64) -> Result<Response<ListFileRequestsV2Result>> {
+        list_v2(self, limit)
+    }
+
+    /// Once a cursor has been retrieved from list_v2, use this to paginate through all file
+    /// requests.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#file_requests-list-continue)
+    pub fn list_continue(&self, cursor: &str) -> Result<Response<ListFileRequestsV2Result>> {
+        list_continue(self, cursor)
+    }
+
+    /// Returns an iterator over all file requests owned by this user, following the cursors of
+    /// file_requests/list/continue.
+    pub fn list_all(&self) -> PaginatedIter<ListFileRequestsPages<FileRequests>> {
+        ListFileRequestsPages {
+                client: self.clone(),
+                limit: MAX_PAGE_LIMIT,
+            }
+            .iter_all()
+    }
+
+    /// Update a file request.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#file_requests-update)
+    pub fn update(&self, id: &str) -> UpdateFileRequestRequestBuilder<FileRequests> {
+        UpdateFileRequestRequestBuilder::new(self, id)
+    }
+}
+
+/**
+ * file request
+ **/
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum GracePeriod {
+    OneDay,
+    TwoDays,
+    SevenDays,
+    ThirtyDays,
+    Always,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct FileRequestDeadline {
+    /// The deadline for the file request, formatted as "%Y-%m-%dT%H:%M:%SZ".
+    pub deadline: String,
+    /// If set, allow uploads after the deadline has passed. These uploads will be marked overdue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_late_uploads: Option<GracePeriod>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct FileRequest {
+    pub id: String,
+    pub url: String,
+    pub title: String,
+    pub created: String,
+    pub is_open: bool,
+    pub file_count: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deadline: Option<FileRequestDeadline>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/**
+ * create
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct CreateFileRequestArgs {
+    pub title: String,
+    pub destination: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deadline: Option<FileRequestDeadline>,
+    pub open: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+pub struct CreateFileRequestRequestBuilder<T> {
+    client: T,
+    args: CreateFileRequestArgs,
+}
+
+impl<T> CreateFileRequestRequestBuilder<T>
+    where T: RPCClient + Clone
+{
+    pub fn new(client: &T,
+               title: &str,
+               destination: &str)
+               -> CreateFileRequestRequestBuilder<T> {
+        CreateFileRequestRequestBuilder {
+            client: client.clone(),
+            args: CreateFileRequestArgs {
+                title: title.to_owned(),
+                destination: destination.to_owned(),
+                deadline: None,
+                open: true,
+                description: None,
+            },
+        }
+    }
+
+    /// Stops accepting uploads at `deadline`, formatted as "%Y-%m-%dT%H:%M:%SZ", unless
+    /// `allow_late_uploads` gives a grace period. Deadlines need a Dropbox Professional or
+    /// Business account.
+    pub fn deadline(&mut self,
+                    deadline: &str,
+                    allow_late_uploads: Option<GracePeriod>)
+                    -> &mut CreateFileRequestRequestBuilder<T> {
+        self.args.deadline = Some(FileRequestDeadline {
+            deadline: deadline.to_owned(),
+            allow_late_uploads: allow_late_uploads,
+        });
+        self
+    }
+
+    /// Whether or not the file request should be open. If the file request is closed, it will
+    /// not accept any file submissions, but it can be opened later. Defaults to true.
+    pub fn open(&mut self, open: bool) -> &mut CreateFileRequestRequestBuilder<T> {
+        self.args.open = open;
+        self
+    }
+
+    pub fn description(&mut self, description: &str) -> &mut CreateFileRequestRequestBuilder<T> {
+        self.args.description = Some(description.to_owned());
+        self
+    }
+
+    pub fn send(&self) -> Result<Response<FileRequest>> {
+        let url = Url::parse(BASE_URL)?.join("create")?;
+        let resp_w_err = self.client.rpc_request(url, &self.args)?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::CreateFileRequestErr(e).into()),
+        }
+    }
+}
+
+/**
+ * get
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct GetFileRequestArgs {
+    pub id: String,
+}
+
+/**
+ * list_v2
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ListFileRequestsArg {
+    pub limit: u64,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ListFileRequestsContinueArg {
+    pub cursor: String,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ListFileRequestsV2Result {
+    pub file_requests: Vec<FileRequest>,
+    pub cursor: String,
+    pub has_more: bool,
+}
+
+/// The pages of file requests returned by file_requests/list_v2 and file_requests/list/continue.
+pub struct ListFileRequestsPages<T> {
+    pub client: T,
+    pub limit: u64,
+}
+
+impl<T: RPCClient> Paginated for ListFileRequestsPages<T> {
+    type Item = FileRequest;
+    type Error = Error;
+
+    fn first_page(&self) -> Result<Page<FileRequest>> {
+        Ok(list_page(list_v2(&self.client, self.limit)?.body))
+    }
+
+    fn next_page(&self, cursor: &str) -> Result<Page<FileRequest>> {
+        Ok(list_page(list_continue(&self.client, cursor)?.body))
+    }
+
+    fn item_key(&self, file_request: &FileRequest) -> String {
+        file_request.id.clone()
+    }
+}
+
+fn list_page(result: ListFileRequestsV2Result) -> Page<FileRequest> {
+    Page {
+        items: result.file_requests,
+        cursor: result.cursor,
+        cursor_expiration: None,
+        has_more: result.has_more,
+    }
+}
+
+pub fn list_v2<T: RPCClient>(client: &T,
+                             limit: u64)
+                             -> Result<Response<ListFileRequestsV2Result>> {
+    let url = Url::parse(BASE_URL)?.join("list_v2")?;
+    let resp_w_err = client.rpc_request(url, &ListFileRequestsArg { limit: limit })?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::GeneralFileRequestsErr(e).into()),
+    }
+}
+
+pub fn list_continue<T: RPCClient>(client: &T,
+                                   cursor: &str)
+                                   -> Result<Response<ListFileRequestsV2Result>> {
+    let url = Url::parse(BASE_URL)?.join("list/continue")?;
+    let resp_w_err = client.rpc_request(url,
+                                        &ListFileRequestsContinueArg {
+                                            cursor: cursor.to_owned(),
+                                        })?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::ListFileRequestsContinueErr(e).into()),
+    }
+}
+
+/**
+ * update
+ **/
+#[derive(PartialEq,Eq,Debug,Clone)]
+pub enum UpdateFileRequestDeadline {
+    /// Do not change the file request's deadline.
+    NoUpdate,
+    /// Change the deadline, or remove it with `None`.
+    Update(Option<FileRequestDeadline>),
+}
+
+impl Serialize for UpdateFileRequestDeadline {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut map = serializer.serialize_map(None)?;
+        match *self {
+            UpdateFileRequestDeadline::NoUpdate => {
+                map.serialize_entry(".tag", "no_update")?;
+            }
+            UpdateFileRequestDeadline::Update(ref deadline) => {
+                map.serialize_entry(".tag", "update")?;
+                if let Some(ref deadline) = *deadline {
+                    map.serialize_entry("deadline", &deadline.deadline)?;
+                    if let Some(ref allow_late_uploads) = deadline.allow_late_uploads {
+                        map.serialize_entry("allow_late_uploads", allow_late_uploads)?;
+                    }
+                }
+            }
+        }
+        map.end()
+    }
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize)]
+pub struct UpdateFileRequestArgs {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<String>,
+    pub deadline: UpdateFileRequestDeadline,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+pub struct UpdateFileRequestRequestBuilder<T> {
+    client: T,
+    args: UpdateFileRequestArgs,
+}
+
+impl<T> UpdateFileRequestRequestBuilder<T>
+    where T: RPCClient + Clone
+{
+    pub fn new(client: &T, id: &str) -> UpdateFileRequestRequestBuilder<T> {
+        UpdateFileRequestRequestBuilder {
+            client: client.clone(),
+            args: UpdateFileRequestArgs {
+                id: id.to_owned(),
+                title: None,
+                destination: None,
+                deadline: UpdateFileRequestDeadline::NoUpdate,
+                open: None,
+                description: None,
+            },
+        }
+    }
+
+    pub fn title(&mut self, title: &str) -> &mut UpdateFileRequestRequestBuilder<T> {
+        self.args.title = Some(title.to_owned());
+        self
+    }
+
+    pub fn destination(&mut self, destination: &str) -> &mut UpdateFileRequestRequestBuilder<T> {
+        self.args.destination = Some(destination.to_owned());
+        self
+    }
+
+    /// Changes the deadline, formatted as "%Y-%m-%dT%H:%M:%SZ".
+    pub fn deadline(&mut self,
+                    deadline: &str,
+                    allow_late_uploads: Option<GracePeriod>)
+                    -> &mut UpdateFileRequestRequestBuilder<T> {
+        self.args.deadline = UpdateFileRequestDeadline::Update(Some(FileRequestDeadline {
+            deadline: deadline.to_owned(),
+            allow_late_uploads: allow_late_uploads,
+        }));
+        self
+    }
+
+    pub fn remove_deadline(&mut self) -> &mut UpdateFileRequestRequestBuilder<T> {
+        self.args.deadline = UpdateFileRequestDeadline::Update(None);
+        self
+    }
+
+    pub fn open(&mut self, open: bool) -> &mut UpdateFileRequestRequestBuilder<T> {
+        self.args.open = Some(open);
+        self
+    }
+
+    pub fn description(&mut self, description: &str) -> &mut UpdateFileRequestRequestBuilder<T> {
+        self.args.description = Some(description.to_owned());
+        self
+    }
+
+    pub fn send(&self) -> Result<Response<FileRequest>> {
+        let url = Url::parse(BASE_URL)?.join("update")?;
+        let resp_w_err = self.client.rpc_request(url, &self.args)?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::FileRequestErr(e).into()),
+        }
+    }
+}
+
+/**
+ * delete and delete_all_closed
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct DeleteFileRequestArgs {
+    pub ids: Vec<String>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct DeleteFileRequestsResult {
+    pub file_requests: Vec<FileRequest>,
+}
+
+/**
+ * count
+ **/
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+pub struct CountFileRequestsResult {
+    pub file_request_count: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use super::*;
+
+    #[test]
+    fn test_update_file_request_deadline_serialization() {
+        assert_eq!(serde_json::to_string(&UpdateFileRequestDeadline::NoUpdate).unwrap(),
+                   r#"{".tag":"no_update"}"#);
+        assert_eq!(serde_json::to_string(&UpdateFileRequestDeadline::Update(None)).unwrap(),
+                   r#"{".tag":"update"}"#);
+
+        let update = UpdateFileRequestDeadline::Update(Some(FileRequestDeadline {
+            deadline: "2018-05-01T00:00:00Z".to_owned(),
+            allow_late_uploads: Some(GracePeriod::OneDay),
+        }));
+        assert_eq!(serde_json::to_string(&update).unwrap(),
+                   r#"{".tag":"update","deadline":"2018-05-01T00:00:00Z","allow_late_uploads":{".tag":"one_day"}}"#);
+    }
+}
@@ -0,0 +1,318 @@
+use reqwest::Url;
+
+use std::time::Duration;
+
+use files::errors::*;
+use files::Metadata;
+use http::{Response, ResponseWithErr};
+use http::RPCClient;
+use http::async_job::{AsyncJob, AsyncJobStatus, LaunchResult, PollError, PollOptions};
+
+/**
+ * copy_batch_v2 and move_batch_v2
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct RelocationPath {
+    pub from_path: String,
+    pub to_path: String,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct RelocationBatchArg {
+    pub entries: Vec<RelocationPath>,
+    pub autorename: bool,
+    /// Only accepted by move_batch_v2.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_ownership_transfer: Option<bool>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum RelocationError {
+    FromLookup { from_lookup: LookupError },
+    FromWrite { from_write: WriteError },
+    To { to: WriteError },
+    CantCopySharedFolder,
+    CantNestSharedFolder,
+    CantMoveFolderIntoItself,
+    TooManyFiles,
+    DuplicatedOrNestedPaths,
+    CantTransferOwnership,
+    InsufficientQuota,
+    InternalError,
+    CantMoveSharedFolder,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum RelocationBatchErrorEntry {
+    RelocationError { relocation_error: RelocationError },
+    InternalError,
+    TooManyWriteOperations,
+}
+
+#[derive(Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum RelocationBatchResultEntry {
+    Success { success: Metadata },
+    Failure { failure: RelocationBatchErrorEntry },
+}
+
+#[derive(Debug,Clone,Serialize,Deserialize)]
+pub struct RelocationBatchV2Result {
+    pub entries: Vec<RelocationBatchResultEntry>,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone)]
+pub enum RelocationBatchKind {
+    Copy,
+    Move,
+}
+
+impl RelocationBatchKind {
+    fn endpoint(&self) -> &'static str {
+        match *self {
+            RelocationBatchKind::Copy => "copy_batch_v2",
+            RelocationBatchKind::Move => "move_batch_v2",
+        }
+    }
+
+    fn check_endpoint(&self) -> &'static str {
+        match *self {
+            RelocationBatchKind::Copy => "copy_batch/check_v2",
+            RelocationBatchKind::Move => "move_batch/check_v2",
+        }
+    }
+}
+
+pub struct RelocationBatchRequestBuilder<T> {
+    client: T,
+    kind: RelocationBatchKind,
+    entries: Vec<RelocationPath>,
+    autorename: bool,
+    allow_ownership_transfer: Option<bool>,
+    poll_options: PollOptions,
+}
+
+impl<T> RelocationBatchRequestBuilder<T>
+    where T: RPCClient + Clone
+{
+    pub fn new(client: &T, kind: RelocationBatchKind) -> RelocationBatchRequestBuilder<T> {
+        RelocationBatchRequestBuilder {
+            client: client.clone(),
+            kind: kind,
+            entries: Vec::new(),
+            autorename: false,
+            allow_ownership_transfer: None,
+            poll_options: PollOptions::default(),
+        }
+    }
+
+    pub fn add_entry(&mut self,
+                     from_path: &str,
+                     to_path: &str)
+                     -> &mut RelocationBatchRequestBuilder<T> {
+        self.entries.push(RelocationPath {
+            from_path: from_path.to_owned(),
+            to_path: to_path.to_owned(),
+        });
+        self
+    }
+
+    /// If there's a conflict with any file, have the Dropbox server try to autorename that file
+    /// to avoid the conflict.
+    pub fn autorename(&mut self, autorename: bool) -> &mut RelocationBatchRequestBuilder<T> {
+        self.autorename = autorename;
+        self
+    }
+
+    /// Allow moves by owner even if it would result in an ownership transfer for the content
+    /// being moved. Ignored for copies.
+    pub fn allow_ownership_transfer(&mut self,
+                                    allow_ownership_transfer: bool)
+                                    -> &mut RelocationBatchRequestBuilder<T> {
+        if self.kind == RelocationBatchKind::Move {
+            self.allow_ownership_transfer = Some(allow_ownership_transfer);
+        }
+        self
+    }
+
+    pub fn poll_interval(&mut self, interval: Duration) -> &mut RelocationBatchRequestBuilder<T> {
+        self.poll_options.interval = interval;
+        self
+    }
+
+    pub fn max_polls(&mut self, max_polls: u32) -> &mut RelocationBatchRequestBuilder<T> {
+        self.poll_options.max_polls = Some(max_polls);
+        self
+    }
+
+    /// Submits the batch and blocks until the job completes, returning the result of each entry
+    /// in the order they were added.
+    pub fn send(&self) -> Result<Vec<RelocationBatchResultEntry>> {
+        let url = Url::parse(super::BASE_URL)?.join(self.kind.endpoint())?;
+        let resp_w_err: ResponseWithErr<LaunchResult<RelocationBatchV2Result>, ()> = self.client
+            .rpc_request(url,
+                         &RelocationBatchArg {
+                             entries: self.entries.clone(),
+                             autorename: self.autorename,
+                             allow_ownership_transfer: self.allow_ownership_transfer,
+                         })?;
+        let launch = match resp_w_err {
+            ResponseWithErr::Ok(r) => r.body,
+            ResponseWithErr::Err(_) => {
+                unreachable!("files: https://api.dropboxapi.com/2/files/{} should not return \
+                              errors",
+                             self.kind.endpoint())
+            }
+        };
+
+        match launch {
+            LaunchResult::Complete(result) => Ok(result.entries),
+            LaunchResult::AsyncJobId { async_job_id } => {
+                let check_url = Url::parse(super::BASE_URL)?.join(self.kind.check_endpoint())?;
+                let status: ResponseWithErr<AsyncJobStatus<RelocationBatchV2Result, ()>,
+                                            PollError> =
+                    AsyncJob::new(&self.client, check_url, &async_job_id)
+                        .options(self.poll_options)
+                        .wait()?;
+                match status {
+                    ResponseWithErr::Ok(Response { body: AsyncJobStatus::Complete(result), .. }) => {
+                        Ok(result.entries)
+                    }
+                    ResponseWithErr::Ok(_) => {
+                        unreachable!("files: {} jobs only complete", self.kind.endpoint())
+                    }
+                    ResponseWithErr::Err(e) => Err(ErrorKind::PollErr(e).into()),
+                }
+            }
+        }
+    }
+}
+
+/**
+ * delete_batch
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct DeleteArg {
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_rev: Option<String>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct DeleteBatchArg {
+    pub entries: Vec<DeleteArg>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum DeleteError {
+    PathLookup { path_lookup: LookupError },
+    PathWrite { path_write: WriteError },
+    TooManyWriteOperations,
+    TooManyFiles,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum DeleteBatchError {
+    TooManyWriteOperations,
+}
+
+#[derive(Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum DeleteBatchResultEntry {
+    Success { metadata: Metadata },
+    Failure { failure: DeleteError },
+}
+
+#[derive(Debug,Clone,Serialize,Deserialize)]
+pub struct DeleteBatchResult {
+    pub entries: Vec<DeleteBatchResultEntry>,
+}
+
+pub struct DeleteBatchRequestBuilder<T> {
+    client: T,
+    entries: Vec<DeleteArg>,
+    poll_options: PollOptions,
+}
+
+impl<T> DeleteBatchRequestBuilder<T>
+    where T: RPCClient + Clone
+{
+    pub fn new(client: &T) -> DeleteBatchRequestBuilder<T> {
+        DeleteBatchRequestBuilder {
+            client: client.clone(),
+            entries: Vec::new(),
+            poll_options: PollOptions::default(),
+        }
+    }
+
+    pub fn add_entry(&mut self, path: &str) -> &mut DeleteBatchRequestBuilder<T> {
+        self.entries.push(DeleteArg {
+            path: path.to_owned(),
+            parent_rev: None,
+        });
+        self
+    }
+
+    /// Deletes the file at `path` only if its latest revision is `parent_rev`.
+    pub fn add_entry_with_rev(&mut self,
+                              path: &str,
+                              parent_rev: &str)
+                              -> &mut DeleteBatchRequestBuilder<T> {
+        self.entries.push(DeleteArg {
+            path: path.to_owned(),
+            parent_rev: Some(parent_rev.to_owned()),
+        });
+        self
+    }
+
+    pub fn poll_interval(&mut self, interval: Duration) -> &mut DeleteBatchRequestBuilder<T> {
+        self.poll_options.interval = interval;
+        self
+    }
+
+    pub fn max_polls(&mut self, max_polls: u32) -> &mut DeleteBatchRequestBuilder<T> {
+        self.poll_options.max_polls = Some(max_polls);
+        self
+    }
+
+    /// Submits the batch and blocks until the job completes, returning the result of each entry
+    /// in the order they were added.
+    pub fn send(&self) -> Result<Vec<DeleteBatchResultEntry>> {
+        let url = Url::parse(super::BASE_URL)?.join("delete_batch")?;
+        let resp_w_err: ResponseWithErr<LaunchResult<DeleteBatchResult>, ()> =
+            self.client.rpc_request(url, &DeleteBatchArg { entries: self.entries.clone() })?;
+        let launch = match resp_w_err {
+            ResponseWithErr::Ok(r) => r.body,
+            ResponseWithErr::Err(_) => {
+                unreachable!("files: https://api.dropboxapi.com/2/files/delete_batch should not \
+                              return errors")
+            }
+        };
+
+        match launch {
+            LaunchResult::Complete(result) => Ok(result.entries),
+            LaunchResult::AsyncJobId { async_job_id } => {
+                let check_url = Url::parse(super::BASE_URL)?.join("delete_batch/check")?;
+                let status: ResponseWithErr<AsyncJobStatus<DeleteBatchResult, DeleteBatchError>,
+                                            PollError> =
+                    AsyncJob::new(&self.client, check_url, &async_job_id)
+                        .options(self.poll_options)
+                        .wait()?;
+                match status {
+                    ResponseWithErr::Ok(Response { body: AsyncJobStatus::Complete(result), .. }) => {
+                        Ok(result.entries)
+                    }
+                    ResponseWithErr::Ok(Response { body: AsyncJobStatus::Failed { failed }, .. }) => {
+                        Err(ErrorKind::DeleteBatchFailed(failed).into())
+                    }
+                    ResponseWithErr::Ok(_) => unreachable!("files: delete_batch job in progress"),
+                    ResponseWithErr::Err(e) => Err(ErrorKind::PollErr(e).into()),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use zip;
+
+use std::env;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use files::errors::*;
+use files::FolderMetadata;
+
+/**
+ * download_zip
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct DownloadZipArg {
+    pub path: String,
+}
+
+#[derive(Debug,Clone,Serialize,Deserialize)]
+pub struct DownloadZipResult {
+    pub metadata: FolderMetadata,
+}
+
+/// Extracts the zip archive read from `reader` into `destination`. Returns the paths of the
+/// extracted files.
+///
+/// The archive is spooled to a temporary file first since the archives generated by
+/// `download_zip` store the sizes of their entries in data descriptors, which can only be found
+/// through the central directory at the end of the archive. Entries whose name would be extracted
+/// outside of `destination` are skipped.
+pub fn extract_zip<R: Read, P: AsRef<Path>>(reader: &mut R,
+                                            destination: P)
+                                            -> Result<Vec<PathBuf>> {
+    let spool_path = env::temp_dir().join(format!("dropbox_rs_download_zip_{}_{}.zip",
+                                                  process::id(),
+                                                  SPOOL_COUNTER.fetch_add(1, Ordering::SeqCst)));
+    let extracted = spool_and_extract(reader, &spool_path, destination.as_ref());
+    if let Err(e) = fs::remove_file(&spool_path) {
+        warn!("[extract_zip] error removing spooled archive {}: {}",
+              spool_path.display(),
+              e);
+    }
+    extracted
+}
+
+static SPOOL_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+fn spool_and_extract<R: Read>(reader: &mut R,
+                              spool_path: &Path,
+                              destination: &Path)
+                              -> Result<Vec<PathBuf>> {
+    let mut spool = OpenOptions::new().read(true).write(true).create_new(true).open(spool_path)?;
+    io::copy(reader, &mut spool)?;
+    spool.seek(SeekFrom::Start(0))?;
+
+    let mut archive = zip::ZipArchive::new(spool)?;
+    let mut extracted = Vec::new();
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        let entry_path = match entry.enclosed_name() {
+            Some(entry_path) => destination.join(entry_path),
+            None => {
+                warn!("[extract_zip] skipping entry with unsafe name {}", entry.name());
+                continue;
+            }
+        };
+
+        if entry.is_dir() {
+            fs::create_dir_all(&entry_path)?;
+        } else {
+            if let Some(parent) = entry_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let mut file = File::create(&entry_path)?;
+            io::copy(&mut entry, &mut file)?;
+            extracted.push(entry_path);
+        }
+    }
+    Ok(extracted)
+}
+
+/**
+ * export
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ExportArg {
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub export_format: Option<String>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ExportMetadata {
+    pub name: String,
+    pub size: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub export_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paper_revision: Option<i64>,
+}
+
+#[derive(Debug,Clone,Serialize,Deserialize)]
+pub struct ExportResult {
+    pub export_metadata: ExportMetadata,
+    pub file_metadata: ::files::FileMetadata,
+}
+
+impl ::http::HasContentHash for ExportResult {
+    fn content_hash(&self) -> Option<&str> {
+        self.export_metadata.export_hash.as_ref().map(|x| x.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate uuid;
+
+    use std::env;
+    use std::fs;
+    use std::io::{Cursor, Read, Write};
+
+    use zip::ZipWriter;
+    use zip::write::FileOptions;
+
+    use super::extract_zip;
+
+    #[test]
+    fn test_extract_zip() {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        writer.add_directory("folder/empty/", FileOptions::default()).unwrap();
+        writer.start_file("folder/a.txt", FileOptions::default()).unwrap();
+        writer.write_all(b"hello").unwrap();
+        writer.start_file("../outside.txt", FileOptions::default()).unwrap();
+        writer.write_all(b"outside").unwrap();
+        let archive = writer.finish().unwrap().into_inner();
+
+        let destination = env::temp_dir().join(format!("dropbox_rs_{}", uuid::Uuid::new_v4()));
+        let extracted = extract_zip(&mut archive.as_slice(), &destination)
+            .expect("error extracting zip");
+
+        assert_eq!(extracted, vec![destination.join("folder/a.txt")]);
+        assert!(destination.join("folder/empty").is_dir());
+        let mut content = String::new();
+        fs::File::open(destination.join("folder/a.txt"))
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        assert_eq!(content, "hello");
+
+        fs::remove_dir_all(&destination).unwrap();
+    }
+
+    // Builds an archive with a single stored entry whose crc and sizes are only written in the
+    // data descriptor following its content, as in the archives generated by download_zip
+    fn data_descriptor_archive(name: &str, content: &[u8], crc: u32) -> Vec<u8> {
+        fn u16_le(out: &mut Vec<u8>, x: u16) {
+            out.extend_from_slice(&[x as u8, (x >> 8) as u8]);
+        }
+        fn u32_le(out: &mut Vec<u8>, x: u32) {
+            out.extend_from_slice(&[x as u8, (x >> 8) as u8, (x >> 16) as u8, (x >> 24) as u8]);
+        }
+
+        let mut archive = Vec::new();
+        u32_le(&mut archive, 0x04034b50);
+        for x in &[20, 0x0008, 0, 0, 0] {
+            u16_le(&mut archive, *x);
+        }
+        for _ in 0..3 {
+            u32_le(&mut archive, 0);
+        }
+        u16_le(&mut archive, name.len() as u16);
+        u16_le(&mut archive, 0);
+        archive.extend_from_slice(name.as_bytes());
+        archive.extend_from_slice(content);
+        for x in &[0x08074b50, crc, content.len() as u32, content.len() as u32] {
+            u32_le(&mut archive, *x);
+        }
+
+        let central_directory_offset = archive.len() as u32;
+        u32_le(&mut archive, 0x02014b50);
+        for x in &[20, 20, 0x0008, 0, 0, 0] {
+            u16_le(&mut archive, *x);
+        }
+        for x in &[crc, content.len() as u32, content.len() as u32] {
+            u32_le(&mut archive, *x);
+        }
+        for x in &[name.len() as u16, 0, 0, 0, 0] {
+            u16_le(&mut archive, *x);
+        }
+        u32_le(&mut archive, 0);
+        u32_le(&mut archive, 0);
+        archive.extend_from_slice(name.as_bytes());
+        let central_directory_size = archive.len() as u32 - central_directory_offset;
+
+        u32_le(&mut archive, 0x06054b50);
+        for x in &[0, 0, 1, 1] {
+            u16_le(&mut archive, *x);
+        }
+        u32_le(&mut archive, central_directory_size);
+        u32_le(&mut archive, central_directory_offset);
+        u16_le(&mut archive, 0);
+        archive
+    }
+
+    #[test]
+    fn test_extract_zip_with_data_descriptors() {
+        let archive = data_descriptor_archive("folder/a.txt", b"hello", 0x3610a686);
+
+        let destination = env::temp_dir().join(format!("dropbox_rs_{}", uuid::Uuid::new_v4()));
+        let extracted = extract_zip(&mut archive.as_slice(), &destination)
+            .expect("error extracting zip");
+
+        assert_eq!(extracted, vec![destination.join("folder/a.txt")]);
+        let mut content = String::new();
+        fs::File::open(destination.join("folder/a.txt"))
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        assert_eq!(content, "hello");
+
+        fs::remove_dir_all(&destination).unwrap();
+    }
+}
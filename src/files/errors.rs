@@ -0,0 +1,443 @@
+use reqwest::StatusCode;
+
+use http::errors::APIError;
+use http::async_job::PollError;
+use files::batch::DeleteBatchError;
+use files::locking::FileLock;
+
+error_chain!{
+    links {
+        Http(::http::errors::Error, ::http::errors::ErrorKind);
+    }
+    foreign_links {
+        Url(::reqwest::UrlError);
+        Reqwest(::reqwest::Error);
+        Utf8(::std::string::FromUtf8Error);
+        Io(::std::io::Error);
+        Json(::serde_json::Error);
+        UrlEncodedSer(::serde_urlencoded::ser::Error);
+        Base64(::base64::DecodeError);
+        Zip(::zip::result::ZipError);
+    }
+    errors {
+        SearchErr(error: APIError<SearchError>) {
+            description("SearchError"),
+            display("{:?}", error)
+        }
+        DownloadErr(error: APIError<DownloadError>) {
+            description("DownloadError"),
+            display("{:?}", error)
+        }
+        DownloadZipErr(error: APIError<DownloadZipError>) {
+            description("DownloadZipError"),
+            display("{:?}", error)
+        }
+        ExportErr(error: APIError<ExportError>) {
+            description("ExportError"),
+            display("{:?}", error)
+        }
+        ListFolderErr(error: APIError<ListFolderError>) {
+            description("ListFolderError"),
+            display("{:?}", error)
+        }
+        ListFolderContinueErr(error: APIError<ListFolderContinueError>) {
+            description("ListFolderContinueError"),
+            display("{:?}", error)
+        }
+        ListFolderLongpollErr(error: APIError<ListFolderLongpollError>) {
+            description("ListFolderLongpollError"),
+            display("{:?}", error)
+        }
+        ListRevisionsErr(error: APIError<ListRevisionsError>) {
+            description("ListRevisionsError"),
+            display("{:?}", error)
+        }
+        RestoreErr(error: APIError<RestoreError>) {
+            description("RestoreError"),
+            display("{:?}", error)
+        }
+        ThumbnailV2Err(error: APIError<ThumbnailV2Error>) {
+            description("ThumbnailV2Error"),
+            display("{:?}", error)
+        }
+        GetThumbnailBatchErr(error: APIError<GetThumbnailBatchError>) {
+            description("GetThumbnailBatchError"),
+            display("{:?}", error)
+        }
+        PreviewErr(error: APIError<PreviewError>) {
+            description("PreviewError"),
+            display("{:?}", error)
+        }
+        GetTemporaryLinkErr(error: APIError<GetTemporaryLinkError>) {
+            description("GetTemporaryLinkError"),
+            display("{:?}", error)
+        }
+        TemporaryUploadLinkErr(status: StatusCode, body: String) {
+            description("The upload to a temporary upload link failed"),
+            display("Temporary upload link returned {}: {}", status, body)
+        }
+        SaveUrlErr(error: APIError<SaveUrlError>) {
+            description("SaveUrlError"),
+            display("{:?}", error)
+        }
+        SaveUrlFailed(error: SaveUrlError) {
+            description("SaveUrlError"),
+            display("{:?}", error)
+        }
+        LockFileErr(error: APIError<LockFileError>) {
+            description("LockFileError"),
+            display("{:?}", error)
+        }
+        LockFileFailed(error: LockFileError) {
+            description("LockFileError"),
+            display("{:?}", error)
+        }
+        LockFileResultNotFound(path: String) {
+            description("A file lock batch didn't return a result for a requested path"),
+            display("Couldn't find the file lock result for: {}", path),
+        }
+        AddTagErr(error: APIError<AddTagError>) {
+            description("AddTagError"),
+            display("{:?}", error)
+        }
+        RemoveTagErr(error: APIError<RemoveTagError>) {
+            description("RemoveTagError"),
+            display("{:?}", error)
+        }
+        BaseTagErr(error: APIError<BaseTagError>) {
+            description("BaseTagError"),
+            display("{:?}", error)
+        }
+        InvalidTagText(tag_text: String) {
+            description("A tag doesn't follow the Dropbox tag rules"),
+            display("Invalid tag {:?}: tags must be 1 to 32 letters, digits or underscores", tag_text)
+        }
+        PollErr(error: APIError<PollError>) {
+            description("PollError"),
+            display("{:?}", error)
+        }
+        DeleteBatchFailed(error: DeleteBatchError) {
+            description("DeleteBatchError"),
+            display("{:?}", error)
+        }
+    }
+}
+
+impl From<APIError<SearchError>> for ErrorKind {
+    fn from(error: APIError<SearchError>) -> Self {
+        ErrorKind::SearchErr(error)
+    }
+}
+
+impl From<APIError<DownloadError>> for ErrorKind {
+    fn from(error: APIError<DownloadError>) -> Self {
+        ErrorKind::DownloadErr(error)
+    }
+}
+
+impl From<APIError<DownloadZipError>> for ErrorKind {
+    fn from(error: APIError<DownloadZipError>) -> Self {
+        ErrorKind::DownloadZipErr(error)
+    }
+}
+
+impl From<APIError<ExportError>> for ErrorKind {
+    fn from(error: APIError<ExportError>) -> Self {
+        ErrorKind::ExportErr(error)
+    }
+}
+
+impl From<APIError<ListFolderError>> for ErrorKind {
+    fn from(error: APIError<ListFolderError>) -> Self {
+        ErrorKind::ListFolderErr(error)
+    }
+}
+
+impl From<APIError<ListFolderContinueError>> for ErrorKind {
+    fn from(error: APIError<ListFolderContinueError>) -> Self {
+        ErrorKind::ListFolderContinueErr(error)
+    }
+}
+
+impl From<APIError<ListFolderLongpollError>> for ErrorKind {
+    fn from(error: APIError<ListFolderLongpollError>) -> Self {
+        ErrorKind::ListFolderLongpollErr(error)
+    }
+}
+
+impl From<APIError<ListRevisionsError>> for ErrorKind {
+    fn from(error: APIError<ListRevisionsError>) -> Self {
+        ErrorKind::ListRevisionsErr(error)
+    }
+}
+
+impl From<APIError<RestoreError>> for ErrorKind {
+    fn from(error: APIError<RestoreError>) -> Self {
+        ErrorKind::RestoreErr(error)
+    }
+}
+
+impl From<APIError<ThumbnailV2Error>> for ErrorKind {
+    fn from(error: APIError<ThumbnailV2Error>) -> Self {
+        ErrorKind::ThumbnailV2Err(error)
+    }
+}
+
+impl From<APIError<GetThumbnailBatchError>> for ErrorKind {
+    fn from(error: APIError<GetThumbnailBatchError>) -> Self {
+        ErrorKind::GetThumbnailBatchErr(error)
+    }
+}
+
+impl From<APIError<PreviewError>> for ErrorKind {
+    fn from(error: APIError<PreviewError>) -> Self {
+        ErrorKind::PreviewErr(error)
+    }
+}
+
+impl From<APIError<GetTemporaryLinkError>> for ErrorKind {
+    fn from(error: APIError<GetTemporaryLinkError>) -> Self {
+        ErrorKind::GetTemporaryLinkErr(error)
+    }
+}
+
+impl From<APIError<SaveUrlError>> for ErrorKind {
+    fn from(error: APIError<SaveUrlError>) -> Self {
+        ErrorKind::SaveUrlErr(error)
+    }
+}
+
+impl From<APIError<LockFileError>> for ErrorKind {
+    fn from(error: APIError<LockFileError>) -> Self {
+        ErrorKind::LockFileErr(error)
+    }
+}
+
+impl From<APIError<AddTagError>> for ErrorKind {
+    fn from(error: APIError<AddTagError>) -> Self {
+        ErrorKind::AddTagErr(error)
+    }
+}
+
+impl From<APIError<RemoveTagError>> for ErrorKind {
+    fn from(error: APIError<RemoveTagError>) -> Self {
+        ErrorKind::RemoveTagErr(error)
+    }
+}
+
+impl From<APIError<BaseTagError>> for ErrorKind {
+    fn from(error: APIError<BaseTagError>) -> Self {
+        ErrorKind::BaseTagErr(error)
+    }
+}
+
+impl From<APIError<PollError>> for ErrorKind {
+    fn from(error: APIError<PollError>) -> Self {
+        ErrorKind::PollErr(error)
+    }
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum LookupError {
+    MalformedPath { malformed_path: Option<String> },
+    NotFound,
+    NotFile,
+    NotFolder,
+    RestrictedContent,
+    UnsupportedContentType,
+    Locked,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum SearchError {
+    Path { path: LookupError },
+    InvalidArgument { invalid_argument: Option<String> },
+    InternalError,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum WriteConflictError {
+    File,
+    Folder,
+    FileAncestor,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum WriteError {
+    MalformedPath { malformed_path: Option<String> },
+    Conflict { conflict: WriteConflictError },
+    NoWritePermission,
+    InsufficientSpace,
+    DisallowedName,
+    TeamFolder,
+    OperationSuppressed,
+    TooManyWriteOperations,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum DownloadError {
+    Path { path: LookupError },
+    UnsupportedFile,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum DownloadZipError {
+    Path { path: LookupError },
+    TooLarge,
+    TooManyFiles,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum ExportError {
+    Path { path: LookupError },
+    NonExportable,
+    InvalidExportFormat,
+    RetryError,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum ListRevisionsError {
+    Path { path: LookupError },
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum RestoreError {
+    PathLookup { path_lookup: LookupError },
+    PathWrite { path_write: WriteError },
+    InvalidRevision,
+    InProgress,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum ThumbnailError {
+    Path { path: LookupError },
+    UnsupportedExtension,
+    UnsupportedImage,
+    ConversionError,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum ThumbnailV2Error {
+    Path { path: LookupError },
+    UnsupportedExtension,
+    UnsupportedImage,
+    ConversionError,
+    AccessDenied,
+    NotFound,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum GetThumbnailBatchError {
+    TooManyFiles,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum PreviewError {
+    Path { path: LookupError },
+    InProgress,
+    UnsupportedExtension,
+    UnsupportedContent,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum GetTemporaryLinkError {
+    Path { path: LookupError },
+    EmailNotVerified,
+    UnsupportedFile,
+    NotAllowed,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum SaveUrlError {
+    Path { path: WriteError },
+    DownloadFailed,
+    InvalidUrl,
+    NotFound,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct LockConflictError {
+    /// The lock that caused the conflict.
+    pub lock: FileLock,
+}
+
+impl LockConflictError {
+    /// The account holding the conflicting lock.
+    pub fn lock_holder_account_id(&self) -> Option<&str> {
+        self.lock.single_user().map(|lock| lock.lock_holder_account_id.as_str())
+    }
+
+    /// When the conflicting lock was created.
+    pub fn created(&self) -> Option<&str> {
+        self.lock.single_user().map(|lock| lock.created.as_str())
+    }
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum LockFileError {
+    PathLookup { path_lookup: LookupError },
+    TooManyWriteOperations,
+    TooManyFiles,
+    NoWritePermission,
+    CannotBeLocked,
+    FileNotShared,
+    LockConflict(LockConflictError),
+    InternalError,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum BaseTagError {
+    Path { path: LookupError },
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum AddTagError {
+    Path { path: LookupError },
+    TooManyTags,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum RemoveTagError {
+    Path { path: LookupError },
+    TagNotPresent,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum ListFolderError {
+    Path { path: LookupError },
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum ListFolderContinueError {
+    Path { path: LookupError },
+    /// The cursor is no longer valid and the folder must be listed again.
+    Reset,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum ListFolderLongpollError {
+    /// The cursor is no longer valid and the folder must be listed again.
+    Reset,
+}
@@ -0,0 +1,236 @@
+use serde_json;
+use reqwest::Url;
+use reqwest::Client as ReqwestClient;
+use reqwest::header::ContentType;
+
+use std::time::Duration;
+
+use files::errors::*;
+use files::Metadata;
+use files::watcher::FolderWatcher;
+use http::{Response, ResponseWithErr};
+use http::RPCClient;
+
+static NOTIFY_BASE_URL: &'static str = "https://notify.dropboxapi.com/2/files/";
+
+/// The longpoll timeout used when none is given, as the Dropbox API does.
+pub const DEFAULT_LONGPOLL_TIMEOUT_SECS: u64 = 30;
+
+/// Dropbox adds up to 90 seconds of random jitter to the longpoll timeout.
+const LONGPOLL_JITTER_SECS: u64 = 90;
+
+/**
+ * list_folder
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ListFolderArg {
+    /// The empty string lists the root folder.
+    pub path: String,
+    pub recursive: bool,
+    pub include_deleted: bool,
+    pub include_has_explicit_shared_members: bool,
+    pub include_mounted_folders: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    /// Lists a folder of a shared link instead, `path` being relative to the root of the link.
+    /// Only non-recursive listing is supported for shared links.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shared_link: Option<SharedLink>,
+}
+
+impl ListFolderArg {
+    pub fn new(path: &str) -> ListFolderArg {
+        ListFolderArg {
+            path: path.to_owned(),
+            recursive: false,
+            include_deleted: false,
+            include_has_explicit_shared_members: false,
+            include_mounted_folders: true,
+            limit: None,
+            shared_link: None,
+        }
+    }
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct SharedLink {
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+}
+
+#[derive(Debug,Clone,Serialize,Deserialize)]
+pub struct ListFolderResult {
+    pub entries: Vec<Metadata>,
+    pub cursor: String,
+    pub has_more: bool,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ListFolderContinueArg {
+    pub cursor: String,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ListFolderGetLatestCursorResult {
+    pub cursor: String,
+}
+
+pub struct ListFolderRequestBuilder<T> {
+    client: T,
+    arg: ListFolderArg,
+}
+
+impl<T> ListFolderRequestBuilder<T>
+    where T: RPCClient + Clone
+{
+    pub fn new(client: &T, path: &str) -> ListFolderRequestBuilder<T> {
+        ListFolderRequestBuilder {
+            client: client.clone(),
+            arg: ListFolderArg::new(path),
+        }
+    }
+
+    /// Lists the contents of all subfolders as well.
+    pub fn recursive(&mut self, recursive: bool) -> &mut ListFolderRequestBuilder<T> {
+        self.arg.recursive = recursive;
+        self
+    }
+
+    /// Returns `Metadata::Deleted` entries for deleted files and folders.
+    pub fn include_deleted(&mut self, include_deleted: bool) -> &mut ListFolderRequestBuilder<T> {
+        self.arg.include_deleted = include_deleted;
+        self
+    }
+
+    pub fn include_has_explicit_shared_members(&mut self,
+                                               include_has_explicit_shared_members: bool)
+                                               -> &mut ListFolderRequestBuilder<T> {
+        self.arg.include_has_explicit_shared_members = include_has_explicit_shared_members;
+        self
+    }
+
+    pub fn include_mounted_folders(&mut self,
+                                   include_mounted_folders: bool)
+                                   -> &mut ListFolderRequestBuilder<T> {
+        self.arg.include_mounted_folders = include_mounted_folders;
+        self
+    }
+
+    /// The maximum number of entries to return per page. This is only a hint, Dropbox may return
+    /// more or fewer entries.
+    pub fn limit(&mut self, limit: u32) -> &mut ListFolderRequestBuilder<T> {
+        self.arg.limit = Some(limit);
+        self
+    }
+
+    /// Lists the folder at `path` inside the shared link `url` rather than in the user's Dropbox.
+    pub fn shared_link(&mut self,
+                       url: &str,
+                       password: Option<&str>)
+                       -> &mut ListFolderRequestBuilder<T> {
+        self.arg.shared_link = Some(SharedLink {
+            url: url.to_owned(),
+            password: password.map(|x| x.to_owned()),
+        });
+        self
+    }
+
+    /// Fetches the first page of entries. Use `Files::list_folder_continue` with the returned
+    /// cursor to fetch the following pages and then the changes made to the folder.
+    pub fn send(&self) -> Result<Response<ListFolderResult>> {
+        list_folder(&self.client, &self.arg)
+    }
+
+    /// Gets a cursor for the current state of the folder without listing its entries.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-list_folder-get_latest_cursor)
+    pub fn get_latest_cursor(&self) -> Result<Response<ListFolderGetLatestCursorResult>> {
+        list_folder_get_latest_cursor(&self.client, &self.arg)
+    }
+
+    /// Returns a `FolderWatcher` yielding the changes made to the folder from now on.
+    pub fn watch(&self) -> FolderWatcher<T> {
+        FolderWatcher::new(&self.client, self.arg.clone())
+    }
+}
+
+pub fn list_folder<T: RPCClient>(client: &T,
+                                 arg: &ListFolderArg)
+                                 -> Result<Response<ListFolderResult>> {
+    let url = Url::parse(super::BASE_URL)?.join("list_folder")?;
+    let resp_w_err = client.rpc_request(url, arg)?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::ListFolderErr(e).into()),
+    }
+}
+
+pub fn list_folder_continue<T: RPCClient>(client: &T,
+                                          cursor: &str)
+                                          -> Result<Response<ListFolderResult>> {
+    let url = Url::parse(super::BASE_URL)?.join("list_folder/continue")?;
+    let resp_w_err = client.rpc_request(url, &ListFolderContinueArg { cursor: cursor.to_owned() })?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::ListFolderContinueErr(e).into()),
+    }
+}
+
+pub fn list_folder_get_latest_cursor<T: RPCClient>
+    (client: &T,
+     arg: &ListFolderArg)
+     -> Result<Response<ListFolderGetLatestCursorResult>> {
+    let url = Url::parse(super::BASE_URL)?.join("list_folder/get_latest_cursor")?;
+    let resp_w_err = client.rpc_request(url, arg)?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::ListFolderErr(e).into()),
+    }
+}
+
+/**
+ * list_folder/longpoll
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ListFolderLongpollArg {
+    pub cursor: String,
+    /// In seconds, between 30 and 480.
+    pub timeout: u64,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ListFolderLongpollResult {
+    pub changes: bool,
+    /// The number of seconds to wait before calling longpoll again.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backoff: Option<u64>,
+}
+
+/// Blocks until there are changes in the folder of `cursor` or `timeout` elapses.
+///
+/// The request goes to the notify host and doesn't need an access token. `timeout` is rounded
+/// down to whole seconds.
+pub fn list_folder_longpoll(cursor: &str,
+                            timeout: Duration)
+                            -> Result<Response<ListFolderLongpollResult>> {
+    let url = Url::parse(NOTIFY_BASE_URL)?.join("list_folder/longpoll")?;
+    let req_arg = serde_json::to_string(&ListFolderLongpollArg {
+            cursor: cursor.to_owned(),
+            timeout: timeout.as_secs(),
+        })?;
+    info!("[Longpoll] [url = {}] [request_body = {}]", url, req_arg);
+
+    let client = ReqwestClient::builder()
+        .timeout(timeout + Duration::from_secs(LONGPOLL_JITTER_SECS))
+        .build()?;
+    let res = client.post(url)
+        .header(ContentType::json())
+        .body(req_arg)
+        .send()?;
+
+    match ResponseWithErr::try_from(res)? {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::ListFolderLongpollErr(e).into()),
+    }
+}
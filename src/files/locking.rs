@@ -0,0 +1,201 @@
+use reqwest::Url;
+
+use files::errors::*;
+use files::Metadata;
+use http::{Response, ResponseWithErr};
+use http::RPCClient;
+
+/**
+ * file locks
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct SingleUserLock {
+    pub created: String,
+    pub lock_holder_account_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lock_holder_team_id: Option<String>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum FileLockContent {
+    Unlocked,
+    SingleUser(SingleUserLock),
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct FileLock {
+    pub content: FileLockContent,
+}
+
+impl FileLock {
+    pub fn single_user(&self) -> Option<&SingleUserLock> {
+        match self.content {
+            FileLockContent::SingleUser(ref lock) => Some(lock),
+            FileLockContent::Unlocked => None,
+        }
+    }
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct FileLockMetadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_lockholder: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lockholder_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lockholder_account_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct LockFileArg {
+    pub path: String,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct LockFileBatchArg {
+    pub entries: Vec<LockFileArg>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct UnlockFileArg {
+    pub path: String,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct UnlockFileBatchArg {
+    pub entries: Vec<UnlockFileArg>,
+}
+
+#[derive(Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum LockFileResultEntry {
+    Success { metadata: Metadata, lock: FileLock },
+    Failure { failure: LockFileError },
+}
+
+#[derive(Debug,Clone,Serialize,Deserialize)]
+pub struct LockFileBatchResult {
+    pub entries: Vec<LockFileResultEntry>,
+}
+
+pub fn lock_file_batch<T: RPCClient>(client: &T,
+                                     paths: &[&str])
+                                     -> Result<Response<LockFileBatchResult>> {
+    let url = Url::parse(super::BASE_URL)?.join("lock_file_batch")?;
+    let entries = paths.iter().map(|path| LockFileArg { path: (*path).to_owned() }).collect();
+    file_lock_request(client, url, &LockFileBatchArg { entries: entries })
+}
+
+pub fn unlock_file_batch<T: RPCClient>(client: &T,
+                                       paths: &[&str])
+                                       -> Result<Response<LockFileBatchResult>> {
+    let url = Url::parse(super::BASE_URL)?.join("unlock_file_batch")?;
+    let entries = paths.iter().map(|path| UnlockFileArg { path: (*path).to_owned() }).collect();
+    file_lock_request(client, url, &UnlockFileBatchArg { entries: entries })
+}
+
+pub fn get_file_lock_batch<T: RPCClient>(client: &T,
+                                         paths: &[&str])
+                                         -> Result<Response<LockFileBatchResult>> {
+    let url = Url::parse(super::BASE_URL)?.join("get_file_lock_batch")?;
+    let entries = paths.iter().map(|path| LockFileArg { path: (*path).to_owned() }).collect();
+    file_lock_request(client, url, &LockFileBatchArg { entries: entries })
+}
+
+fn file_lock_request<T, A>(client: &T, url: Url, arg: &A) -> Result<Response<LockFileBatchResult>>
+    where T: RPCClient,
+          A: ::serde::Serialize
+{
+    let resp_w_err = client.rpc_request(url, arg)?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::LockFileErr(e).into()),
+    }
+}
+
+fn single_lock_result(path: &str, resp: Response<LockFileBatchResult>) -> Result<FileLock> {
+    match resp.body.entries.into_iter().next() {
+        Some(LockFileResultEntry::Success { lock, .. }) => Ok(lock),
+        Some(LockFileResultEntry::Failure { failure }) => {
+            Err(ErrorKind::LockFileFailed(failure).into())
+        }
+        None => Err(ErrorKind::LockFileResultNotFound(path.to_owned()).into()),
+    }
+}
+
+/// A lock on a file which is released when the guard is dropped.
+///
+/// Errors while unlocking on drop are only logged, use `unlock` to handle them.
+pub struct FileLockGuard<T>
+    where T: RPCClient
+{
+    client: T,
+    path: String,
+    lock: FileLock,
+    locked: bool,
+}
+
+impl<T> FileLockGuard<T>
+    where T: RPCClient + Clone
+{
+    /// Locks the file at `path`, failing with `ErrorKind::LockFileFailed` if it couldn't be
+    /// locked, for example with a `LockFileError::LockConflict` if someone else holds the lock.
+    pub fn acquire(client: &T, path: &str) -> Result<FileLockGuard<T>> {
+        let lock = single_lock_result(path, lock_file_batch(client, &[path])?)?;
+        Ok(FileLockGuard {
+            client: client.clone(),
+            path: path.to_owned(),
+            lock: lock,
+            locked: true,
+        })
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The lock as it was when it was last acquired, renewed or refreshed.
+    pub fn lock(&self) -> &FileLock {
+        &self.lock
+    }
+
+    /// Locks the file again, which keeps the lock if it is still held by the current user.
+    pub fn renew(&mut self) -> Result<&FileLock> {
+        let resp = lock_file_batch(&self.client, &[&self.path])?;
+        self.lock = single_lock_result(&self.path, resp)?;
+        Ok(&self.lock)
+    }
+
+    /// Fetches the current state of the lock, for example to find out whether it was broken by
+    /// someone else.
+    pub fn refresh(&mut self) -> Result<&FileLock> {
+        let resp = get_file_lock_batch(&self.client, &[&self.path])?;
+        self.lock = single_lock_result(&self.path, resp)?;
+        Ok(&self.lock)
+    }
+
+    /// Releases the lock.
+    pub fn unlock(mut self) -> Result<()> {
+        self.locked = false;
+        let resp = unlock_file_batch(&self.client, &[&self.path])?;
+        single_lock_result(&self.path, resp).map(|_| ())
+    }
+}
+
+impl<T> Drop for FileLockGuard<T>
+    where T: RPCClient
+{
+    fn drop(&mut self) {
+        if !self.locked {
+            return;
+        }
+        let unlocked = unlock_file_batch(&self.client, &[&self.path])
+            .and_then(|resp| single_lock_result(&self.path, resp));
+        if let Err(e) = unlocked {
+            warn!("[FileLockGuard] [path = {}] error unlocking file: {}", self.path, e);
+        }
+    }
+}
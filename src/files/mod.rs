@@ -0,0 +1,623 @@
+//! [Dropbox Files Documentation](https://www.dropbox.com/developers/documentation/http/documentation#files)
+//!
+//! This namespace contains endpoints and data types for basic file operations.
+//!
+//!
+
+pub mod batch;
+pub mod download_zip;
+pub mod errors;
+pub mod list_folder;
+pub mod locking;
+pub mod revisions;
+pub mod save_url;
+pub mod search;
+pub mod tags;
+pub mod temporary_links;
+pub mod thumbnails;
+pub mod watcher;
+
+use serde::de::DeserializeOwned;
+use reqwest::Url;
+
+use std::collections::{HashMap, HashSet};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::time::Duration;
+
+use self::errors::*;
+use http::{Response, ContentResponse, HasContentHash};
+use http::{ResponseWithErr, ContentResponseWithErr};
+use http::{RPCClient, ContentDownloadClient};
+use http::async_job::{AsyncJob, AsyncJobStatus, PollError};
+
+use self::batch::{RelocationBatchRequestBuilder, RelocationBatchKind, RelocationBatchV2Result,
+                  DeleteBatchRequestBuilder, DeleteBatchResult, DeleteBatchError};
+use self::download_zip::{DownloadZipArg, DownloadZipResult, ExportArg, ExportResult};
+use self::list_folder::{ListFolderArg, ListFolderRequestBuilder, ListFolderResult,
+                        ListFolderLongpollResult};
+use self::locking::{FileLockGuard, FileLockMetadata, LockFileBatchResult};
+use self::revisions::{ListRevisionsArg, ListRevisionsMode, ListRevisionsResult, RestoreArg};
+use self::save_url::SaveUrlRequestBuilder;
+use self::search::{SearchRequestBuilder, SearchV2Result};
+use self::tags::{AddTagArg, RemoveTagArg, GetTagsArg, GetTagsResult, TagChanges};
+use self::temporary_links::{GetTemporaryLinkArg, GetTemporaryLinkResult,
+                            GetTemporaryUploadLinkArg, GetTemporaryUploadLinkResult};
+use self::watcher::FolderWatcher;
+use self::thumbnails::{ThumbnailRequestBuilder, ThumbnailBatchRequestBuilder, PathOrLink,
+                       PreviewArg};
+
+static BASE_URL: &'static str = "https://api.dropboxapi.com/2/files/";
+static CONTENT_BASE_URL: &'static str = "https://content.dropboxapi.com/2/files/";
+
+/// A client to interface with the endpoints in the Files namespace of the Dropbox APIs
+#[derive(Debug,Clone)]
+pub struct Files {
+    access_token: Rc<String>,
+}
+
+impl ::http::HasAccessToken for Files {
+    fn access_token(&self) -> &str {
+        self.access_token.as_str()
+    }
+}
+
+impl Files {
+    pub fn new(access_token: Rc<String>) -> Files {
+        Files { access_token: Rc::clone(&access_token) }
+    }
+
+    /// Copies multiple files or folders to different locations at once in the user's Dropbox.
+    /// `send` submits the batch and polls the launched job until it completes.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-copy_batch_v2)
+    pub fn copy_batch(&self) -> RelocationBatchRequestBuilder<Files> {
+        RelocationBatchRequestBuilder::new(self, RelocationBatchKind::Copy)
+    }
+
+    /// Returns the status of an asynchronous job for copy_batch_v2.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-copy_batch-check_v2)
+    pub fn copy_batch_check(&self,
+                            async_job_id: &str)
+                            -> Result<Response<AsyncJobStatus<RelocationBatchV2Result, ()>>> {
+        self.check_job("copy_batch/check_v2", async_job_id)
+    }
+
+    /// Deletes multiple files or folders at once.
+    /// `send` submits the batch and polls the launched job until it completes.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-delete_batch)
+    pub fn delete_batch(&self) -> DeleteBatchRequestBuilder<Files> {
+        DeleteBatchRequestBuilder::new(self)
+    }
+
+    /// Returns the status of an asynchronous job for delete_batch.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-delete_batch-check)
+    pub fn delete_batch_check
+        (&self,
+         async_job_id: &str)
+         -> Result<Response<AsyncJobStatus<DeleteBatchResult, DeleteBatchError>>> {
+        self.check_job("delete_batch/check", async_job_id)
+    }
+
+    /// Downloads a file from a user's Dropbox. The path also accepts a file id or a revision as
+    /// `rev:<rev>`.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-download)
+    pub fn download(&self, path: &str) -> Result<ContentResponse<FileMetadata>> {
+        let url = Url::parse(CONTENT_BASE_URL)?.join("download")?;
+        let resp_w_err = self.content_download(url, &DownloadArg { path: path.to_owned() })?;
+        match resp_w_err {
+            ContentResponseWithErr::Ok(r) => Ok(r),
+            ContentResponseWithErr::Err(e) => Err(ErrorKind::DownloadErr(e).into()),
+        }
+    }
+
+    /// Downloads the revision `rev` of a file into `writer` and returns the metadata of that
+    /// revision.
+    pub fn download_revision<W: Write>(&self,
+                                       rev: &str,
+                                       writer: &mut W)
+                                       -> Result<FileMetadata> {
+        let mut download_resp = self.download(&format!("rev:{}", rev))?;
+        io::copy(&mut download_resp, writer)?;
+        Ok(download_resp.body)
+    }
+
+    /// Gets a preview for a file. Previews are only generated for PDF, HTML and office document
+    /// files. PDF previews are returned for office documents and HTML previews for spreadsheets.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-get_preview)
+    pub fn get_preview(&self,
+                       path: &str,
+                       rev: Option<&str>)
+                       -> Result<ContentResponse<FileMetadata>> {
+        let url = Url::parse(CONTENT_BASE_URL)?.join("get_preview")?;
+        let resp_w_err = self.content_download(url,
+                              &PreviewArg {
+                                  path: path.to_owned(),
+                                  rev: rev.map(|x| x.to_owned()),
+                              })?;
+        match resp_w_err {
+            ContentResponseWithErr::Ok(r) => Ok(r),
+            ContentResponseWithErr::Err(e) => Err(ErrorKind::PreviewErr(e).into()),
+        }
+    }
+
+    /// Adds a tag to each of the given paths. Every path can have up to 20 tags.
+    ///
+    /// `tag_text` is validated before any request is sent.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-tags-add)
+    pub fn tags_add(&self, paths: &[&str], tag_text: &str) -> Result<()> {
+        tags::validate_tag_text(tag_text)?;
+        for path in paths {
+            let url = Url::parse(BASE_URL)?.join("tags/add")?;
+            let resp_w_err: ResponseWithErr<(), _> = self.rpc_request(url,
+                             &AddTagArg {
+                                 path: (*path).to_owned(),
+                                 tag_text: tag_text.to_owned(),
+                             })?;
+            if let ResponseWithErr::Err(e) = resp_w_err {
+                return Err(ErrorKind::AddTagErr(e).into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes a tag from each of the given paths.
+    ///
+    /// `tag_text` is validated before any request is sent.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-tags-remove)
+    pub fn tags_remove(&self, paths: &[&str], tag_text: &str) -> Result<()> {
+        tags::validate_tag_text(tag_text)?;
+        for path in paths {
+            let url = Url::parse(BASE_URL)?.join("tags/remove")?;
+            let resp_w_err: ResponseWithErr<(), _> = self.rpc_request(url,
+                             &RemoveTagArg {
+                                 path: (*path).to_owned(),
+                                 tag_text: tag_text.to_owned(),
+                             })?;
+            if let ResponseWithErr::Err(e) = resp_w_err {
+                return Err(ErrorKind::RemoveTagErr(e).into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Gets the tags of each of the given paths.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-tags-get)
+    pub fn tags_get(&self, paths: &[&str]) -> Result<Response<GetTagsResult>> {
+        let url = Url::parse(BASE_URL)?.join("tags/get")?;
+        let resp_w_err = self.rpc_request(url,
+                         &GetTagsArg { paths: paths.iter().map(|x| (*x).to_owned()).collect() })?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::BaseTagErr(e).into()),
+        }
+    }
+
+    /// Makes each path in `desired` tagged with exactly its set of tags, adding and removing only
+    /// the tags which differ from the current ones. All tags are validated before any request is
+    /// sent.
+    pub fn tags_set(&self, desired: &HashMap<String, HashSet<String>>) -> Result<TagChanges> {
+        for tag_text in desired.values().flat_map(|tags| tags.iter()) {
+            tags::validate_tag_text(tag_text)?;
+        }
+
+        let paths = desired.keys().map(|x| x.as_str()).collect::<Vec<_>>();
+        let current = self.tags_get(&paths)?;
+        let changes = tags::tag_changes(&current.body, desired);
+
+        for &(ref path, ref tag_text) in &changes.added {
+            self.tags_add(&[path], tag_text)?;
+        }
+        for &(ref path, ref tag_text) in &changes.removed {
+            self.tags_remove(&[path], tag_text)?;
+        }
+        Ok(changes)
+    }
+
+    /// Gets a temporary link to stream content of a file. This link will expire in four hours and
+    /// afterwards you will get 410 Gone.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-get_temporary_link)
+    pub fn get_temporary_link(&self, path: &str) -> Result<Response<GetTemporaryLinkResult>> {
+        let url = Url::parse(BASE_URL)?.join("get_temporary_link")?;
+        let resp_w_err = self.rpc_request(url, &GetTemporaryLinkArg { path: path.to_owned() })?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::GetTemporaryLinkErr(e).into()),
+        }
+    }
+
+    /// Gets a one-time use temporary upload link to upload a file to a Dropbox location.
+    /// `duration` must be between 60 seconds and 4 hours.
+    ///
+    /// Use `temporary_links::upload_to_temporary_link` to upload content to the returned link.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-get_temporary_upload_link)
+    pub fn get_temporary_upload_link(&self,
+                                     commit_info: &CommitInfo,
+                                     duration: Duration)
+                                     -> Result<Response<GetTemporaryUploadLinkResult>> {
+        let url = Url::parse(BASE_URL)?.join("get_temporary_upload_link")?;
+        let resp_w_err: ResponseWithErr<_, ()> = self.rpc_request(url,
+                         &GetTemporaryUploadLinkArg {
+                             commit_info: commit_info.clone(),
+                             duration: duration.as_secs() as f64 +
+                                       duration.subsec_nanos() as f64 / 1_000_000_000.0,
+                         })?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(_) => {
+                unreachable!("files: https://api.dropboxapi.com/2/files/get_temporary_upload_link \
+                              should not return errors")
+            }
+        }
+    }
+
+    /// Gets a thumbnail for an image. Thumbnails are only generated for jpg, jpeg, png, tiff,
+    /// tif, gif, webp, ppm and bmp files under 20MB.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-get_thumbnail_v2)
+    pub fn get_thumbnail(&self, path: &str) -> ThumbnailRequestBuilder<Files> {
+        ThumbnailRequestBuilder::new(self, PathOrLink::Path { path: path.to_owned() })
+    }
+
+    /// Gets thumbnails for a list of images, up to 25 at once.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-get_thumbnail_batch)
+    pub fn get_thumbnail_batch(&self) -> ThumbnailBatchRequestBuilder<Files> {
+        ThumbnailBatchRequestBuilder::new(self)
+    }
+
+    /// Downloads a folder from the user's Dropbox, as a zip file. The folder must be less than 20
+    /// GB in size and have fewer than 10,000 total files.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-download_zip)
+    pub fn download_zip(&self, path: &str) -> Result<ContentResponse<DownloadZipResult>> {
+        let url = Url::parse(CONTENT_BASE_URL)?.join("download_zip")?;
+        let resp_w_err = self.content_download(url, &DownloadZipArg { path: path.to_owned() })?;
+        match resp_w_err {
+            ContentResponseWithErr::Ok(r) => Ok(r),
+            ContentResponseWithErr::Err(e) => Err(ErrorKind::DownloadZipErr(e).into()),
+        }
+    }
+
+    /// Downloads a folder as a zip file and extracts it into `destination`. Returns the metadata
+    /// of the folder and the paths of the extracted files.
+    pub fn download_zip_to_dir<P: AsRef<Path>>(&self,
+                                               path: &str,
+                                               destination: P)
+                                               -> Result<(FolderMetadata, Vec<PathBuf>)> {
+        let mut download_resp = self.download_zip(path)?;
+        let extracted = download_zip::extract_zip(&mut download_resp, destination)?;
+        Ok((download_resp.body.metadata, extracted))
+    }
+
+    /// Exports a file which can't be downloaded directly, such as Paper docs or Google Docs. Use
+    /// one of the formats in `FileMetadata::export_info` or `None` for the default `export_as`.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-export)
+    pub fn export(&self,
+                  path: &str,
+                  export_format: Option<&str>)
+                  -> Result<ContentResponse<ExportResult>> {
+        let url = Url::parse(CONTENT_BASE_URL)?.join("export")?;
+        let resp_w_err = self.content_download(url,
+                              &ExportArg {
+                                  path: path.to_owned(),
+                                  export_format: export_format.map(|x| x.to_owned()),
+                              })?;
+        match resp_w_err {
+            ContentResponseWithErr::Ok(r) => Ok(r),
+            ContentResponseWithErr::Err(e) => Err(ErrorKind::ExportErr(e).into()),
+        }
+    }
+
+    /// Starts returning the contents of a folder. `send` fetches the first page of entries.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-list_folder)
+    pub fn list_folder(&self, path: &str) -> ListFolderRequestBuilder<Files> {
+        ListFolderRequestBuilder::new(self, path)
+    }
+
+    /// Once a cursor has been retrieved from list_folder, use this to paginate through all files
+    /// and retrieve updates to the folder.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-list_folder-continue)
+    pub fn list_folder_continue(&self, cursor: &str) -> Result<Response<ListFolderResult>> {
+        list_folder::list_folder_continue(self, cursor)
+    }
+
+    /// A longpoll endpoint to wait for changes on an account. `timeout` must be between 30
+    /// seconds and 8 minutes.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-list_folder-longpoll)
+    pub fn list_folder_longpoll(&self,
+                                cursor: &str,
+                                timeout: Duration)
+                                -> Result<Response<ListFolderLongpollResult>> {
+        list_folder::list_folder_longpoll(cursor, timeout)
+    }
+
+    /// Watches the folder at `path` for changes. See `FolderWatcher`.
+    pub fn watch_folder(&self, path: &str) -> FolderWatcher<Files> {
+        FolderWatcher::new(self, ListFolderArg::new(path))
+    }
+
+    /// Locks the files at the given paths. A locked file is writable only by the lock holder.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-lock_file_batch)
+    pub fn lock_file_batch(&self, paths: &[&str]) -> Result<Response<LockFileBatchResult>> {
+        locking::lock_file_batch(self, paths)
+    }
+
+    /// Unlocks the files at the given paths.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-unlock_file_batch)
+    pub fn unlock_file_batch(&self, paths: &[&str]) -> Result<Response<LockFileBatchResult>> {
+        locking::unlock_file_batch(self, paths)
+    }
+
+    /// Returns the lock metadata for the files at the given paths.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-get_file_lock_batch)
+    pub fn get_file_lock_batch(&self, paths: &[&str]) -> Result<Response<LockFileBatchResult>> {
+        locking::get_file_lock_batch(self, paths)
+    }
+
+    /// Locks a single file and returns a guard which unlocks it when dropped.
+    pub fn lock_file(&self, path: &str) -> Result<FileLockGuard<Files>> {
+        FileLockGuard::acquire(self, path)
+    }
+
+    /// Returns revisions for files based on a file path or a file id. The file path or file id is
+    /// identified from the latest file entry at the given file path or id.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-list_revisions)
+    pub fn list_revisions(&self,
+                          path: &str,
+                          mode: ListRevisionsMode,
+                          limit: u64)
+                          -> Result<Response<ListRevisionsResult>> {
+        let url = Url::parse(BASE_URL)?.join("list_revisions")?;
+        let resp_w_err = self.rpc_request(url,
+                         &ListRevisionsArg {
+                             path: path.to_owned(),
+                             mode: mode,
+                             limit: limit,
+                         })?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::ListRevisionsErr(e).into()),
+        }
+    }
+
+    /// Moves multiple files or folders to different locations at once in the user's Dropbox.
+    /// `send` submits the batch and polls the launched job until it completes.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-move_batch_v2)
+    pub fn move_batch(&self) -> RelocationBatchRequestBuilder<Files> {
+        RelocationBatchRequestBuilder::new(self, RelocationBatchKind::Move)
+    }
+
+    /// Returns the status of an asynchronous job for move_batch_v2.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-move_batch-check_v2)
+    pub fn move_batch_check(&self,
+                            async_job_id: &str)
+                            -> Result<Response<AsyncJobStatus<RelocationBatchV2Result, ()>>> {
+        self.check_job("move_batch/check_v2", async_job_id)
+    }
+
+    /// Restores a specific revision of a file to the given path.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-restore)
+    pub fn restore(&self, path: &str, rev: &str) -> Result<Response<FileMetadata>> {
+        let url = Url::parse(BASE_URL)?.join("restore")?;
+        let resp_w_err = self.rpc_request(url,
+                         &RestoreArg {
+                             path: path.to_owned(),
+                             rev: rev.to_owned(),
+                         })?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::RestoreErr(e).into()),
+        }
+    }
+
+    /// Saves the data at the specified URL into a file in the user's Dropbox. `send` starts the
+    /// server-side fetch and blocks until it completes or fails.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-save_url)
+    pub fn save_url(&self, path: &str, url: &str) -> SaveUrlRequestBuilder<Files> {
+        SaveUrlRequestBuilder::new(self, path, url)
+    }
+
+    /// Checks the status of a save_url job.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-save_url-check_job_status)
+    pub fn save_url_check_job_status(&self,
+                                     async_job_id: &str)
+                                     -> Result<Response<AsyncJobStatus<FileMetadata, SaveUrlError>>> {
+        self.check_job("save_url/check_job_status", async_job_id)
+    }
+
+    /// Searches for files and folders.
+    ///
+    /// Note: Recent changes will be reflected in search results within a few seconds.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-search_v2)
+    pub fn search(&self, query: &str) -> SearchRequestBuilder<Files> {
+        SearchRequestBuilder::new(self, query)
+    }
+
+    /// Once a cursor has been retrieved from files/search_v2, use this to paginate through all search results.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#files-search-continue_v2)
+    pub fn search_continue(&self, cursor: &str) -> Result<Response<SearchV2Result>> {
+        search::search_continue_v2(self, cursor)
+    }
+
+    fn check_job<R, F>(&self,
+                       check_endpoint: &str,
+                       async_job_id: &str)
+                       -> Result<Response<AsyncJobStatus<R, F>>>
+        where R: DeserializeOwned,
+              F: DeserializeOwned
+    {
+        let url = Url::parse(BASE_URL)?.join(check_endpoint)?;
+        let resp_w_err: ResponseWithErr<_, PollError> = AsyncJob::new(self, url, async_job_id)
+            .check()?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::PollErr(e).into()),
+        }
+    }
+}
+
+/**
+ * Metadata
+ **/
+#[derive(Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum Metadata {
+    File(FileMetadata),
+    Folder(FolderMetadata),
+    Deleted(DeletedMetadata),
+}
+
+impl Metadata {
+    pub fn name(&self) -> &str {
+        match *self {
+            Metadata::File(ref file) => &file.name,
+            Metadata::Folder(ref folder) => &folder.name,
+            Metadata::Deleted(ref deleted) => &deleted.name,
+        }
+    }
+
+    pub fn path_lower(&self) -> Option<&str> {
+        match *self {
+            Metadata::File(ref file) => file.path_lower.as_ref().map(|x| x.as_str()),
+            Metadata::Folder(ref folder) => folder.path_lower.as_ref().map(|x| x.as_str()),
+            Metadata::Deleted(ref deleted) => deleted.path_lower.as_ref().map(|x| x.as_str()),
+        }
+    }
+}
+
+#[derive(Debug,Clone,Serialize,Deserialize)]
+pub struct FileMetadata {
+    pub name: String,
+    pub id: String,
+    pub client_modified: String,
+    pub server_modified: String,
+    pub rev: String,
+    pub size: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path_lower: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path_display: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_downloadable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_explicit_shared_members: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
+    /// Set when the file can't be downloaded directly and must be exported with `Files::export`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub export_info: Option<ExportInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_lock_info: Option<FileLockMetadata>,
+}
+
+impl HasContentHash for FileMetadata {
+    fn content_hash(&self) -> Option<&str> {
+        self.content_hash.as_ref().map(|x| x.as_str())
+    }
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ExportInfo {
+    /// The format to which the file is exported by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub export_as: Option<String>,
+    /// The formats to which the file can be exported.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub export_options: Option<Vec<String>>,
+}
+
+#[derive(Debug,Clone,Serialize,Deserialize)]
+pub struct FolderMetadata {
+    pub name: String,
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path_lower: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path_display: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shared_folder_id: Option<String>,
+}
+
+#[derive(Debug,Clone,Serialize,Deserialize)]
+pub struct DeletedMetadata {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path_lower: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path_display: Option<String>,
+}
+
+/**
+ * commit info
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum WriteMode {
+    /// Never overwrite the existing file.
+    Add,
+    /// Always overwrite the existing file.
+    Overwrite,
+    /// Overwrite if the given rev matches the existing file's rev.
+    Update { update: String },
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct CommitInfo {
+    pub path: String,
+    pub mode: WriteMode,
+    pub autorename: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_modified: Option<String>,
+    pub mute: bool,
+    pub strict_conflict: bool,
+}
+
+impl CommitInfo {
+    /// Commits to `path` without overwriting an existing file, as the Dropbox API does by
+    /// default.
+    pub fn new(path: &str) -> CommitInfo {
+        CommitInfo {
+            path: path.to_owned(),
+            mode: WriteMode::Add,
+            autorename: false,
+            client_modified: None,
+            mute: false,
+            strict_conflict: false,
+        }
+    }
+}
+
+/**
+ * download
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct DownloadArg {
+    pub path: String,
+}
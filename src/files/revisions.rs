@@ -0,0 +1,39 @@
+use files::FileMetadata;
+
+/**
+ * list revisions
+ **/
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ListRevisionsMode {
+    /// Returns revisions with the same file path as identified by the latest file entry at the
+    /// given file path or id.
+    Path,
+    /// Returns revisions with the same file id as identified by the latest file entry at the
+    /// given file path or id.
+    Id,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ListRevisionsArg {
+    pub path: String,
+    pub mode: ListRevisionsMode,
+    pub limit: u64,
+}
+
+#[derive(Debug,Clone,Serialize,Deserialize)]
+pub struct ListRevisionsResult {
+    pub is_deleted: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_deleted: Option<String>,
+    pub entries: Vec<FileMetadata>,
+}
+
+/**
+ * restore
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct RestoreArg {
+    pub path: String,
+    pub rev: String,
+}
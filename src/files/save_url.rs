@@ -0,0 +1,86 @@
+use reqwest::Url;
+
+use std::time::Duration;
+
+use files::errors::*;
+use files::FileMetadata;
+use http::{Response, ResponseWithErr};
+use http::RPCClient;
+use http::async_job::{AsyncJob, AsyncJobStatus, LaunchResult, PollError, PollOptions};
+
+/**
+ * save_url
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct SaveUrlArg {
+    pub path: String,
+    pub url: String,
+}
+
+pub struct SaveUrlRequestBuilder<T> {
+    client: T,
+    path: String,
+    url: String,
+    poll_options: PollOptions,
+}
+
+impl<T> SaveUrlRequestBuilder<T>
+    where T: RPCClient + Clone
+{
+    pub fn new(client: &T, path: &str, url: &str) -> SaveUrlRequestBuilder<T> {
+        SaveUrlRequestBuilder {
+            client: client.clone(),
+            path: path.to_owned(),
+            url: url.to_owned(),
+            poll_options: PollOptions::default(),
+        }
+    }
+
+    pub fn poll_interval(&mut self, interval: Duration) -> &mut SaveUrlRequestBuilder<T> {
+        self.poll_options.interval = interval;
+        self
+    }
+
+    pub fn max_polls(&mut self, max_polls: u32) -> &mut SaveUrlRequestBuilder<T> {
+        self.poll_options.max_polls = Some(max_polls);
+        self
+    }
+
+    /// Starts saving the url and blocks until Dropbox finished fetching it, returning the
+    /// metadata of the saved file.
+    pub fn send(&self) -> Result<FileMetadata> {
+        let url = Url::parse(super::BASE_URL)?.join("save_url")?;
+        let resp_w_err: ResponseWithErr<LaunchResult<FileMetadata>, _> = self.client
+            .rpc_request(url,
+                         &SaveUrlArg {
+                             path: self.path.clone(),
+                             url: self.url.clone(),
+                         })?;
+        let launch = match resp_w_err {
+            ResponseWithErr::Ok(r) => r.body,
+            ResponseWithErr::Err(e) => return Err(ErrorKind::SaveUrlErr(e).into()),
+        };
+
+        match launch {
+            LaunchResult::Complete(metadata) => Ok(metadata),
+            LaunchResult::AsyncJobId { async_job_id } => {
+                let check_url = Url::parse(super::BASE_URL)?.join("save_url/check_job_status")?;
+                let status: ResponseWithErr<AsyncJobStatus<FileMetadata, SaveUrlError>,
+                                            PollError> =
+                    AsyncJob::new(&self.client, check_url, &async_job_id)
+                        .options(self.poll_options)
+                        .wait()?;
+                match status {
+                    ResponseWithErr::Ok(Response { body: AsyncJobStatus::Complete(metadata), .. }) => {
+                        Ok(metadata)
+                    }
+                    ResponseWithErr::Ok(Response { body: AsyncJobStatus::Failed { failed }, .. }) => {
+                        Err(ErrorKind::SaveUrlFailed(failed).into())
+                    }
+                    ResponseWithErr::Ok(_) => unreachable!("files: save_url job in progress"),
+                    ResponseWithErr::Err(e) => Err(ErrorKind::PollErr(e).into()),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,284 @@
+use reqwest::Url;
+
+use std::collections::VecDeque;
+
+use files::errors::*;
+use files::Metadata;
+use http::{Response, ResponseWithErr};
+use http::RPCClient;
+
+/**
+ * search
+ **/
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchOrderBy {
+    Relevance,
+    LastModifiedTime,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileStatus {
+    Active,
+    Deleted,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileCategory {
+    Image,
+    Document,
+    Pdf,
+    Spreadsheet,
+    Presentation,
+    Audio,
+    Video,
+    Folder,
+    Paper,
+    Others,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Default,Serialize,Deserialize)]
+pub struct SearchOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_by: Option<SearchOrderBy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_status: Option<FileStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_extensions: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_categories: Option<Vec<FileCategory>>,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+pub struct SearchMatchFieldOptions {
+    pub include_highlights: bool,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct SearchV2Arg {
+    pub query: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<SearchOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub match_field_options: Option<SearchMatchFieldOptions>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct SearchV2ContinueArg {
+    pub cursor: String,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum SearchMatchTypeV2 {
+    Filename,
+    FileContent,
+    FilenameAndContent,
+    ImageContent,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct HighlightSpan {
+    pub highlight_str: String,
+    pub is_highlighted: bool,
+}
+
+#[derive(Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum MetadataV2 {
+    Metadata { metadata: Metadata },
+}
+
+#[derive(Debug,Clone,Serialize,Deserialize)]
+pub struct SearchMatchV2 {
+    pub metadata: MetadataV2,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub match_type: Option<SearchMatchTypeV2>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highlight_spans: Option<Vec<HighlightSpan>>,
+}
+
+impl SearchMatchV2 {
+    pub fn metadata(&self) -> &Metadata {
+        match self.metadata {
+            MetadataV2::Metadata { ref metadata } => metadata,
+        }
+    }
+}
+
+#[derive(Debug,Clone,Serialize,Deserialize)]
+pub struct SearchV2Result {
+    pub matches: Vec<SearchMatchV2>,
+    pub has_more: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+pub struct SearchRequestBuilder<T> {
+    client: T,
+    query: String,
+    options: SearchOptions,
+    include_highlights: Option<bool>,
+}
+
+impl<T> SearchRequestBuilder<T>
+    where T: RPCClient + Clone
+{
+    pub fn new(client: &T, query: &str) -> SearchRequestBuilder<T> {
+        SearchRequestBuilder {
+            client: client.clone(),
+            query: query.to_owned(),
+            options: SearchOptions::default(),
+            include_highlights: None,
+        }
+    }
+
+    /// Scopes the search to a path in the user's Dropbox.
+    pub fn path(&mut self, path: &str) -> &mut SearchRequestBuilder<T> {
+        self.options.path = Some(path.to_owned());
+        self
+    }
+
+    /// The maximum number of search results to return per page.
+    pub fn max_results(&mut self, max_results: u64) -> &mut SearchRequestBuilder<T> {
+        self.options.max_results = Some(max_results);
+        self
+    }
+
+    pub fn order_by(&mut self, order_by: SearchOrderBy) -> &mut SearchRequestBuilder<T> {
+        self.options.order_by = Some(order_by);
+        self
+    }
+
+    pub fn file_status(&mut self, file_status: FileStatus) -> &mut SearchRequestBuilder<T> {
+        self.options.file_status = Some(file_status);
+        self
+    }
+
+    pub fn filename_only(&mut self, filename_only: bool) -> &mut SearchRequestBuilder<T> {
+        self.options.filename_only = Some(filename_only);
+        self
+    }
+
+    pub fn file_extensions(&mut self, file_extensions: &[&str]) -> &mut SearchRequestBuilder<T> {
+        self.options.file_extensions =
+            Some(file_extensions.iter().map(|x| (*x).to_owned()).collect());
+        self
+    }
+
+    pub fn file_categories(&mut self,
+                           file_categories: &[FileCategory])
+                           -> &mut SearchRequestBuilder<T> {
+        self.options.file_categories = Some(file_categories.to_vec());
+        self
+    }
+
+    /// Returns the highlighted spans of each match in `SearchMatchV2::highlight_spans`.
+    pub fn include_highlights(&mut self, include_highlights: bool) -> &mut SearchRequestBuilder<T> {
+        self.include_highlights = Some(include_highlights);
+        self
+    }
+
+    /// Fetches the first page of results. Use `Files::search_continue` with the returned cursor to
+    /// fetch the following pages or `iter` to follow them automatically.
+    pub fn send(&self) -> Result<Response<SearchV2Result>> {
+        search_v2(&self.client, &self.build_arg())
+    }
+
+    /// Returns an iterator over every match, fetching the following pages as needed.
+    pub fn iter(&self) -> SearchIter<T> {
+        SearchIter {
+            client: self.client.clone(),
+            arg: Some(self.build_arg()),
+            cursor: None,
+            matches: VecDeque::new(),
+            done: false,
+        }
+    }
+
+    fn build_arg(&self) -> SearchV2Arg {
+        SearchV2Arg {
+            query: self.query.clone(),
+            options: Some(self.options.clone()),
+            match_field_options: self.include_highlights
+                .map(|x| SearchMatchFieldOptions { include_highlights: x }),
+        }
+    }
+}
+
+/// An iterator over search matches which follows `has_more` and `cursor` to fetch the next page.
+///
+/// Iteration stops after yielding the first error.
+pub struct SearchIter<T> {
+    client: T,
+    arg: Option<SearchV2Arg>,
+    cursor: Option<String>,
+    matches: VecDeque<SearchMatchV2>,
+    done: bool,
+}
+
+impl<T> Iterator for SearchIter<T>
+    where T: RPCClient
+{
+    type Item = Result<SearchMatchV2>;
+
+    fn next(&mut self) -> Option<Result<SearchMatchV2>> {
+        loop {
+            if let Some(search_match) = self.matches.pop_front() {
+                return Some(Ok(search_match));
+            }
+            if self.done {
+                return None;
+            }
+
+            let page = match (self.arg.take(), self.cursor.take()) {
+                (Some(arg), _) => search_v2(&self.client, &arg),
+                (None, Some(cursor)) => search_continue_v2(&self.client, &cursor),
+                (None, None) => {
+                    self.done = true;
+                    return None;
+                }
+            };
+
+            match page {
+                Ok(resp) => {
+                    self.done = !resp.body.has_more;
+                    self.cursor = resp.body.cursor;
+                    self.matches.extend(resp.body.matches);
+                }
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+pub fn search_v2<T: RPCClient>(client: &T, arg: &SearchV2Arg) -> Result<Response<SearchV2Result>> {
+    let url = Url::parse(super::BASE_URL)?.join("search_v2")?;
+    let resp_w_err = client.rpc_request(url, arg)?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::SearchErr(e).into()),
+    }
+}
+
+pub fn search_continue_v2<T: RPCClient>(client: &T,
+                                    cursor: &str)
+                                    -> Result<Response<SearchV2Result>> {
+    let url = Url::parse(super::BASE_URL)?.join("search/continue_v2")?;
+    let resp_w_err = client.rpc_request(url, &SearchV2ContinueArg { cursor: cursor.to_owned() })?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::SearchErr(e).into()),
+    }
+}
@@ -0,0 +1,156 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use files::errors::*;
+
+/**
+ * tags
+ **/
+/// The maximum length of a tag.
+pub const MAX_TAG_TEXT_LENGTH: usize = 32;
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct AddTagArg {
+    pub path: String,
+    pub tag_text: String,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct RemoveTagArg {
+    pub path: String,
+    pub tag_text: String,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct GetTagsArg {
+    pub paths: Vec<String>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct UserGeneratedTag {
+    pub tag_text: String,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum Tag {
+    UserGeneratedTag(UserGeneratedTag),
+}
+
+impl Tag {
+    pub fn tag_text(&self) -> &str {
+        match *self {
+            Tag::UserGeneratedTag(ref tag) => &tag.tag_text,
+        }
+    }
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct PathToTags {
+    pub path: String,
+    pub tags: Vec<Tag>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct GetTagsResult {
+    pub paths_to_tags: Vec<PathToTags>,
+}
+
+/// The tags added and removed to reach a desired set of tags, as `(path, tag_text)` pairs.
+#[derive(PartialEq,Eq,Debug,Clone,Default)]
+pub struct TagChanges {
+    pub added: Vec<(String, String)>,
+    pub removed: Vec<(String, String)>,
+}
+
+/// Checks `tag_text` against the rules of the Dropbox API: between 1 and 32 characters which are
+/// all letters, digits or underscores. Fails with `ErrorKind::InvalidTagText` otherwise.
+pub fn validate_tag_text(tag_text: &str) -> Result<()> {
+    let length = tag_text.chars().count();
+    let valid_chars = tag_text.chars().all(|c| c.is_alphanumeric() || c == '_');
+    if length == 0 || length > MAX_TAG_TEXT_LENGTH || !valid_chars {
+        return Err(ErrorKind::InvalidTagText(tag_text.to_owned()).into());
+    }
+    Ok(())
+}
+
+/// Computes the tags to add and remove so that each path in `desired` ends up with exactly its
+/// set of tags. Tags are compared case insensitively since Dropbox stores them in lowercase.
+pub fn tag_changes(current: &GetTagsResult,
+                   desired: &HashMap<String, HashSet<String>>)
+                   -> TagChanges {
+    let current_tags = current.paths_to_tags
+        .iter()
+        .map(|path_to_tags| {
+            let tags = path_to_tags.tags
+                .iter()
+                .map(|tag| tag.tag_text().to_lowercase())
+                .collect::<BTreeSet<_>>();
+            (path_to_tags.path.to_lowercase(), tags)
+        })
+        .collect::<HashMap<_, _>>();
+    let no_tags = BTreeSet::new();
+
+    let mut paths = desired.keys().collect::<Vec<_>>();
+    paths.sort();
+
+    let mut changes = TagChanges::default();
+    for path in paths {
+        let current = current_tags.get(&path.to_lowercase()).unwrap_or(&no_tags);
+        let wanted = desired[path].iter().map(|tag| tag.to_lowercase()).collect::<BTreeSet<_>>();
+
+        for tag in wanted.difference(current) {
+            changes.added.push((path.clone(), tag.clone()));
+        }
+        for tag in current.difference(&wanted) {
+            changes.removed.push((path.clone(), tag.clone()));
+        }
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use super::*;
+
+    #[test]
+    fn test_validate_tag_text() {
+        assert!(validate_tag_text("reviewed").is_ok());
+        assert!(validate_tag_text("phase_2").is_ok());
+        assert!(validate_tag_text("").is_err());
+        assert!(validate_tag_text("with space").is_err());
+        assert!(validate_tag_text("with-dash").is_err());
+        assert!(validate_tag_text(&"a".repeat(MAX_TAG_TEXT_LENGTH)).is_ok());
+        assert!(validate_tag_text(&"a".repeat(MAX_TAG_TEXT_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn test_tag_changes() {
+        let current = GetTagsResult {
+            paths_to_tags: vec![PathToTags {
+                                    path: "/a.txt".to_owned(),
+                                    tags: vec![Tag::UserGeneratedTag(UserGeneratedTag {
+                                                   tag_text: "ingested".to_owned(),
+                                               }),
+                                               Tag::UserGeneratedTag(UserGeneratedTag {
+                                                   tag_text: "stale".to_owned(),
+                                               })],
+                                },
+                                PathToTags {
+                                    path: "/b.txt".to_owned(),
+                                    tags: vec![],
+                                }],
+        };
+        let mut desired = HashMap::new();
+        desired.insert("/a.txt".to_owned(),
+                       vec!["Ingested", "reviewed"].into_iter().map(String::from).collect());
+        desired.insert("/b.txt".to_owned(), HashSet::new());
+
+        let changes = tag_changes(&current, &desired);
+
+        assert_eq!(changes.added,
+                   vec![("/a.txt".to_owned(), "reviewed".to_owned())]);
+        assert_eq!(changes.removed, vec![("/a.txt".to_owned(), "stale".to_owned())]);
+    }
+}
@@ -0,0 +1,70 @@
+use reqwest::{Url, Body, Client};
+use reqwest::header::ContentType;
+use serde_json;
+
+use std::io::Read;
+
+use files::errors::*;
+use files::{FileMetadata, CommitInfo};
+
+/**
+ * get_temporary_link
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct GetTemporaryLinkArg {
+    pub path: String,
+}
+
+#[derive(Debug,Clone,Serialize,Deserialize)]
+pub struct GetTemporaryLinkResult {
+    pub metadata: FileMetadata,
+    pub link: String,
+}
+
+/**
+ * get_temporary_upload_link
+ **/
+#[derive(Debug,Clone,Serialize,Deserialize)]
+pub struct GetTemporaryUploadLinkArg {
+    pub commit_info: CommitInfo,
+    /// How long before this link expires, in seconds.
+    pub duration: f64,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct GetTemporaryUploadLinkResult {
+    pub link: String,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct TemporaryUploadLinkResult {
+    #[serde(rename = "content-hash")]
+    pub content_hash: String,
+}
+
+/// Uploads `contents` to a link returned by files/get_temporary_upload_link.
+///
+/// No access token is needed, so links can be handed out to machines without Dropbox
+/// credentials. Returns the content hash of the uploaded data, which can be compared with
+/// `content_hash::hash_file`.
+pub fn upload_to_temporary_link<S: Into<Body>>(link: &str,
+                                               contents: S)
+                                               -> Result<TemporaryUploadLinkResult> {
+    let url = Url::parse(link)?;
+    info!("[TemporaryUploadLink] [url = {}]", url);
+
+    let client = Client::new();
+    let mut res = client.post(url)
+        .header(ContentType::octet_stream())
+        .body(contents)
+        .send()?;
+
+    let status = res.status();
+    let mut body = String::new();
+    res.read_to_string(&mut body)?;
+    if status.is_success() {
+        Ok(serde_json::from_str(body.as_str())?)
+    } else {
+        Err(ErrorKind::TemporaryUploadLinkErr(status, body).into())
+    }
+}
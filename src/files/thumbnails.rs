@@ -0,0 +1,250 @@
+use base64;
+use reqwest::Url;
+use reqwest::Response as ReqwestResponse;
+
+use files::errors::*;
+use files::FileMetadata;
+use http::{ContentResponse, ResponseWithErr, ContentResponseWithErr};
+use http::{RPCClient, ContentDownloadClient};
+
+/**
+ * get_thumbnail_v2
+ **/
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThumbnailFormat {
+    Jpeg,
+    Png,
+    Webp,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThumbnailSize {
+    W32h32,
+    W64h64,
+    W128h128,
+    W256h256,
+    W480h320,
+    W640h480,
+    W960h640,
+    W1024h768,
+    W2048h1536,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThumbnailMode {
+    /// Scale down the image to fit within the given size.
+    Strict,
+    /// Scale down the image to fit within the given size or its transpose.
+    Bestfit,
+    /// Scale down the image to completely cover the given size or its transpose.
+    FitoneBestfit,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct SharedLinkFileInfo {
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum PathOrLink {
+    Path { path: String },
+    Link { link: SharedLinkFileInfo },
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ThumbnailV2Arg {
+    pub resource: PathOrLink,
+    pub format: ThumbnailFormat,
+    pub size: ThumbnailSize,
+    pub mode: ThumbnailMode,
+}
+
+#[derive(Debug,Clone,Serialize,Deserialize)]
+pub struct MinimalFileLinkMetadata {
+    pub url: String,
+    pub rev: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+#[derive(Debug,Clone,Serialize,Deserialize)]
+pub struct PreviewResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_metadata: Option<FileMetadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_metadata: Option<MinimalFileLinkMetadata>,
+}
+
+pub struct ThumbnailRequestBuilder<T> {
+    client: T,
+    resource: PathOrLink,
+    format: ThumbnailFormat,
+    size: ThumbnailSize,
+    mode: ThumbnailMode,
+}
+
+impl<T> ThumbnailRequestBuilder<T>
+    where T: ContentDownloadClient<ReqwestResponse> + Clone
+{
+    /// Defaults to a 64 by 64 jpeg thumbnail in strict mode, as the Dropbox API does.
+    pub fn new(client: &T, resource: PathOrLink) -> ThumbnailRequestBuilder<T> {
+        ThumbnailRequestBuilder {
+            client: client.clone(),
+            resource: resource,
+            format: ThumbnailFormat::Jpeg,
+            size: ThumbnailSize::W64h64,
+            mode: ThumbnailMode::Strict,
+        }
+    }
+
+    pub fn format(&mut self, format: ThumbnailFormat) -> &mut ThumbnailRequestBuilder<T> {
+        self.format = format;
+        self
+    }
+
+    pub fn size(&mut self, size: ThumbnailSize) -> &mut ThumbnailRequestBuilder<T> {
+        self.size = size;
+        self
+    }
+
+    pub fn mode(&mut self, mode: ThumbnailMode) -> &mut ThumbnailRequestBuilder<T> {
+        self.mode = mode;
+        self
+    }
+
+    pub fn send(&self) -> Result<ContentResponse<PreviewResult>> {
+        let url = Url::parse(super::CONTENT_BASE_URL)?.join("get_thumbnail_v2")?;
+        let resp_w_err = self.client
+            .content_download(url,
+                              &ThumbnailV2Arg {
+                                  resource: self.resource.clone(),
+                                  format: self.format,
+                                  size: self.size,
+                                  mode: self.mode,
+                              })?;
+        match resp_w_err {
+            ContentResponseWithErr::Ok(r) => Ok(r),
+            ContentResponseWithErr::Err(e) => Err(ErrorKind::ThumbnailV2Err(e).into()),
+        }
+    }
+}
+
+/**
+ * get_thumbnail_batch
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ThumbnailArg {
+    pub path: String,
+    pub format: ThumbnailFormat,
+    pub size: ThumbnailSize,
+    pub mode: ThumbnailMode,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct GetThumbnailBatchArg {
+    pub entries: Vec<ThumbnailArg>,
+}
+
+#[derive(Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum GetThumbnailBatchResultEntry {
+    /// `thumbnail` is the base64 encoded thumbnail content.
+    Success {
+        metadata: FileMetadata,
+        thumbnail: String,
+    },
+    Failure { failure: ThumbnailError },
+}
+
+#[derive(Debug,Clone,Serialize,Deserialize)]
+pub struct GetThumbnailBatchResult {
+    pub entries: Vec<GetThumbnailBatchResultEntry>,
+}
+
+/// A `GetThumbnailBatchResultEntry` with its thumbnail decoded.
+#[derive(Debug,Clone)]
+pub enum ThumbnailBatchEntry {
+    Success {
+        metadata: FileMetadata,
+        thumbnail: Vec<u8>,
+    },
+    Failure(ThumbnailError),
+}
+
+pub struct ThumbnailBatchRequestBuilder<T> {
+    client: T,
+    entries: Vec<ThumbnailArg>,
+}
+
+impl<T> ThumbnailBatchRequestBuilder<T>
+    where T: RPCClient + Clone
+{
+    pub fn new(client: &T) -> ThumbnailBatchRequestBuilder<T> {
+        ThumbnailBatchRequestBuilder {
+            client: client.clone(),
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn add_entry(&mut self,
+                     path: &str,
+                     format: ThumbnailFormat,
+                     size: ThumbnailSize,
+                     mode: ThumbnailMode)
+                     -> &mut ThumbnailBatchRequestBuilder<T> {
+        self.entries.push(ThumbnailArg {
+            path: path.to_owned(),
+            format: format,
+            size: size,
+            mode: mode,
+        });
+        self
+    }
+
+    /// Fetches the thumbnails and decodes them, returning an entry per requested thumbnail in the
+    /// order they were added.
+    pub fn send(&self) -> Result<Vec<ThumbnailBatchEntry>> {
+        let url = Url::parse(super::CONTENT_BASE_URL)?.join("get_thumbnail_batch")?;
+        let resp_w_err: ResponseWithErr<GetThumbnailBatchResult, _> =
+            self.client.rpc_request(url, &GetThumbnailBatchArg { entries: self.entries.clone() })?;
+        let result = match resp_w_err {
+            ResponseWithErr::Ok(r) => r.body,
+            ResponseWithErr::Err(e) => return Err(ErrorKind::GetThumbnailBatchErr(e).into()),
+        };
+
+        result.entries
+            .into_iter()
+            .map(|entry| match entry {
+                GetThumbnailBatchResultEntry::Success { metadata, thumbnail } => {
+                    Ok(ThumbnailBatchEntry::Success {
+                        metadata: metadata,
+                        thumbnail: base64::decode(&thumbnail)?,
+                    })
+                }
+                GetThumbnailBatchResultEntry::Failure { failure } => {
+                    Ok(ThumbnailBatchEntry::Failure(failure))
+                }
+            })
+            .collect()
+    }
+}
+
+/**
+ * get_preview
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct PreviewArg {
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rev: Option<String>,
+}
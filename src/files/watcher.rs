@@ -0,0 +1,208 @@
+//! Watches a folder for changes with files/list_folder/longpoll instead of polling
+//! files/list_folder/continue on a timer.
+//!
+
+use std::io;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use cursor_store::{CursorStore, StoredCursor};
+use files::errors::*;
+use files::errors::ErrorKind::{ListFolderContinueErr, ListFolderLongpollErr};
+use files::Metadata;
+use files::list_folder::{self, ListFolderArg, DEFAULT_LONGPOLL_TIMEOUT_SECS};
+use http::RPCClient;
+
+/// A batch of changes yielded by a `FolderWatcher`.
+#[derive(Debug,Clone)]
+pub enum FolderChanges {
+    /// The entries which changed since the previous batch.
+    Changed(Vec<Metadata>),
+    /// The cursor was reset by Dropbox, so the folder was listed again from scratch. The entries
+    /// are the whole contents of the folder and anything missing from them has been removed.
+    Reset(Vec<Metadata>),
+}
+
+impl FolderChanges {
+    pub fn entries(&self) -> &[Metadata] {
+        match *self {
+            FolderChanges::Changed(ref entries) |
+            FolderChanges::Reset(ref entries) => entries,
+        }
+    }
+}
+
+/// An iterator blocking on files/list_folder/longpoll until the watched folder changes, then
+/// yielding the changed entries.
+///
+/// The watcher starts from the latest cursor of the folder, unless one is given with `cursor`,
+/// and waits for the `backoff` requested by Dropbox between longpolls. Errors are yielded without
+/// losing the cursor, so iteration can go on after a transient failure.
+pub struct FolderWatcher<T> {
+    client: T,
+    arg: ListFolderArg,
+    cursor: Option<String>,
+    checkpoint: Option<(Box<CursorStore>, String)>,
+    timeout: Duration,
+    backoff_until: Option<Instant>,
+}
+
+impl<T> FolderWatcher<T>
+    where T: RPCClient + Clone
+{
+    pub fn new(client: &T, arg: ListFolderArg) -> FolderWatcher<T> {
+        FolderWatcher {
+            client: client.clone(),
+            arg: arg,
+            cursor: None,
+            checkpoint: None,
+            timeout: Duration::from_secs(DEFAULT_LONGPOLL_TIMEOUT_SECS),
+            backoff_until: None,
+        }
+    }
+
+    /// Resumes watching from a cursor returned by a previous `FolderWatcher` or list_folder call.
+    pub fn cursor(&mut self, cursor: &str) -> &mut FolderWatcher<T> {
+        self.cursor = Some(cursor.to_owned());
+        self
+    }
+
+    /// Resumes from the cursor saved in `store` under `key`, and saves the cursor there each time
+    /// the watcher is asked for the next batch of changes, i.e. once the previous batch has been
+    /// handled. A batch being handled when the process stops is yielded again on resume.
+    pub fn checkpoint<S>(&mut self, store: S, key: &str) -> io::Result<&mut FolderWatcher<T>>
+        where S: CursorStore + 'static
+    {
+        if let Some(cursor) = store.load_unexpired(key)? {
+            info!("[FolderWatcher] [key = {}] resuming from saved cursor", key);
+            self.cursor = Some(cursor.value);
+        }
+        self.checkpoint = Some((Box::new(store), key.to_owned()));
+        Ok(self)
+    }
+
+    /// How long each longpoll waits for changes, between 30 seconds and 8 minutes.
+    pub fn timeout(&mut self, timeout: Duration) -> &mut FolderWatcher<T> {
+        self.timeout = timeout;
+        self
+    }
+
+    /// The cursor the next longpoll will use, to be persisted to resume watching later.
+    pub fn current_cursor(&self) -> Option<&str> {
+        self.cursor.as_ref().map(|x| x.as_str())
+    }
+
+    fn latest_cursor(&self) -> Result<String> {
+        Ok(list_folder::list_folder_get_latest_cursor(&self.client, &self.arg)?.body.cursor)
+    }
+
+    fn wait_for_backoff(&mut self) {
+        if let Some(backoff_until) = self.backoff_until.take() {
+            let now = Instant::now();
+            if backoff_until > now {
+                thread::sleep(backoff_until - now);
+            }
+        }
+    }
+
+    /// Fetches every page of changes after `cursor`, returning them with the new cursor.
+    fn changes_since(&self, cursor: &str) -> Result<(Vec<Metadata>, String)> {
+        let mut entries = Vec::new();
+        let mut cursor = cursor.to_owned();
+        loop {
+            let page = list_folder::list_folder_continue(&self.client, &cursor)?.body;
+            entries.extend(page.entries);
+            cursor = page.cursor;
+            if !page.has_more {
+                return Ok((entries, cursor));
+            }
+        }
+    }
+
+    /// Lists the whole folder again, returning its entries with the new cursor.
+    fn relist(&self) -> Result<(Vec<Metadata>, String)> {
+        let first = list_folder::list_folder(&self.client, &self.arg)?.body;
+        if !first.has_more {
+            return Ok((first.entries, first.cursor));
+        }
+        let (more, cursor) = self.changes_since(&first.cursor)?;
+        let mut entries = first.entries;
+        entries.extend(more);
+        Ok((entries, cursor))
+    }
+
+    fn save_checkpoint(&self) -> io::Result<()> {
+        match (&self.checkpoint, &self.cursor) {
+            (&Some((ref store, ref key)), &Some(ref cursor)) => {
+                store.save(key, &StoredCursor::new(cursor, None))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn next_changes(&mut self) -> Result<FolderChanges> {
+        self.save_checkpoint()?;
+        loop {
+            let cursor = match self.cursor.clone() {
+                Some(cursor) => cursor,
+                None => {
+                    let cursor = self.latest_cursor()?;
+                    self.cursor = Some(cursor.clone());
+                    self.save_checkpoint()?;
+                    cursor
+                }
+            };
+
+            self.wait_for_backoff();
+            let changes = match list_folder::list_folder_longpoll(&cursor, self.timeout) {
+                Ok(resp) => {
+                    if let Some(backoff) = resp.body.backoff {
+                        self.backoff_until = Some(Instant::now() + Duration::from_secs(backoff));
+                    }
+                    if !resp.body.changes {
+                        continue;
+                    }
+                    self.changes_since(&cursor)
+                }
+                Err(Error(ListFolderLongpollErr(ref e), _)) if e.error ==
+                                                                ListFolderLongpollError::Reset => {
+                    return self.reset();
+                }
+                Err(e) => return Err(e),
+            };
+
+            match changes {
+                Ok((entries, new_cursor)) => {
+                    self.cursor = Some(new_cursor);
+                    if !entries.is_empty() {
+                        return Ok(FolderChanges::Changed(entries));
+                    }
+                }
+                Err(Error(ListFolderContinueErr(ref e), _)) if e.error ==
+                                                                ListFolderContinueError::Reset => {
+                    return self.reset();
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn reset(&mut self) -> Result<FolderChanges> {
+        info!("[FolderWatcher] [path = {}] cursor reset, listing the folder again",
+              self.arg.path);
+        let (entries, cursor) = self.relist()?;
+        self.cursor = Some(cursor);
+        Ok(FolderChanges::Reset(entries))
+    }
+}
+
+impl<T> Iterator for FolderWatcher<T>
+    where T: RPCClient + Clone
+{
+    type Item = Result<FolderChanges>;
+
+    /// Blocks until the folder changes. Never returns `None`.
+    fn next(&mut self) -> Option<Result<FolderChanges>> {
+        Some(self.next_changes())
+    }
+}
@@ -0,0 +1,209 @@
+//! Helpers for the endpoints which launch a job on the Dropbox servers and return an
+//! `async_job_id`. The status of the job is then polled through a `/check` endpoint taking a
+//! `PollArg` until it either completes or fails.
+//!
+
+use serde::de::DeserializeOwned;
+use reqwest::Url;
+
+use std::thread;
+use std::time::Duration;
+
+use http::{Response, ResponseWithErr, RPCClient};
+use http::errors::*;
+
+/// The result of an endpoint which may either complete synchronously or launch an async job.
+#[derive(Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum LaunchResult<T> {
+    AsyncJobId { async_job_id: String },
+    Complete(T),
+}
+
+/// The status of an async job returned by `/check` endpoints.
+///
+/// Jobs which can't fail are represented with `F = ()`.
+#[derive(Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum AsyncJobStatus<T, F> {
+    InProgress,
+    Complete(T),
+    Failed { failed: F },
+}
+
+/// The `LaunchResult` of the endpoints whose job completes without a value.
+///
+/// `LaunchResult<()>` can't be used since serde doesn't read a unit newtype variant from an
+/// internally tagged enum.
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum LaunchEmptyResult {
+    AsyncJobId { async_job_id: String },
+    Complete,
+}
+
+/// The `AsyncJobStatus` of the jobs which complete without a value.
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum PollEmptyResult<F> {
+    InProgress,
+    Complete,
+    Failed { failed: F },
+}
+
+/// The status of a job returned by a `/check` endpoint.
+pub trait JobStatus {
+    fn is_in_progress(&self) -> bool;
+}
+
+impl<T, F> JobStatus for AsyncJobStatus<T, F> {
+    fn is_in_progress(&self) -> bool {
+        match *self {
+            AsyncJobStatus::InProgress => true,
+            _ => false,
+        }
+    }
+}
+
+impl<F> JobStatus for PollEmptyResult<F> {
+    fn is_in_progress(&self) -> bool {
+        match *self {
+            PollEmptyResult::InProgress => true,
+            _ => false,
+        }
+    }
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct PollArg {
+    pub async_job_id: String,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum PollError {
+    InvalidAsyncJobId,
+    InternalError,
+}
+
+/// How often and how many times an async job is polled before giving up.
+#[derive(PartialEq,Eq,Debug,Copy,Clone)]
+pub struct PollOptions {
+    pub interval: Duration,
+    /// `None` polls until the job is no longer in progress.
+    pub max_polls: Option<u32>,
+}
+
+impl Default for PollOptions {
+    fn default() -> PollOptions {
+        PollOptions {
+            interval: Duration::from_secs(1),
+            max_polls: None,
+        }
+    }
+}
+
+/// A job launched on the Dropbox servers which can be checked or waited upon.
+pub struct AsyncJob<T> {
+    client: T,
+    check_url: Url,
+    async_job_id: String,
+    options: PollOptions,
+}
+
+impl<T> AsyncJob<T>
+    where T: RPCClient + Clone
+{
+    pub fn new(client: &T, check_url: Url, async_job_id: &str) -> AsyncJob<T> {
+        AsyncJob {
+            client: client.clone(),
+            check_url: check_url,
+            async_job_id: async_job_id.to_owned(),
+            options: PollOptions::default(),
+        }
+    }
+
+    pub fn async_job_id(&self) -> &str {
+        &self.async_job_id
+    }
+
+    pub fn options(&mut self, options: PollOptions) -> &mut AsyncJob<T> {
+        self.options = options;
+        self
+    }
+
+    pub fn poll_interval(&mut self, interval: Duration) -> &mut AsyncJob<T> {
+        self.options.interval = interval;
+        self
+    }
+
+    pub fn max_polls(&mut self, max_polls: u32) -> &mut AsyncJob<T> {
+        self.options.max_polls = Some(max_polls);
+        self
+    }
+
+    /// Returns the current status of the job.
+    pub fn check<S, E>(&self) -> Result<ResponseWithErr<S, E>>
+        where S: JobStatus + DeserializeOwned,
+              E: DeserializeOwned
+    {
+        self.client.rpc_request(self.check_url.clone(),
+                                &PollArg { async_job_id: self.async_job_id.clone() })
+    }
+
+    /// Blocks until the job is no longer in progress and returns its final status.
+    ///
+    /// Fails with `ErrorKind::AsyncJobTimeout` once `max_polls` checks returned `in_progress`.
+    pub fn wait<S, E>(&self) -> Result<ResponseWithErr<S, E>>
+        where S: JobStatus + DeserializeOwned,
+              E: DeserializeOwned
+    {
+        let mut polls = 0;
+        loop {
+            match self.check::<S, E>()? {
+                ResponseWithErr::Ok(Response { ref body, .. }) if body.is_in_progress() => {}
+                status => return Ok(status),
+            }
+
+            polls += 1;
+            if let Some(max_polls) = self.options.max_polls {
+                if polls >= max_polls {
+                    return Err(ErrorKind::AsyncJobTimeout(self.async_job_id.clone()).into());
+                }
+            }
+            debug!("[AsyncJob] [async_job_id = {}] in progress after {} polls",
+                   self.async_job_id,
+                   polls);
+            thread::sleep(self.options.interval);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use super::*;
+
+    #[test]
+    fn test_empty_result_deserialization() {
+        let launch: LaunchEmptyResult = serde_json::from_str(r#"{".tag": "complete"}"#)
+            .expect("error deserializing launch result");
+        assert_eq!(launch, LaunchEmptyResult::Complete);
+
+        let launch: LaunchEmptyResult =
+            serde_json::from_str(r#"{".tag": "async_job_id", "async_job_id": "34g93hh34h04y"}"#)
+                .expect("error deserializing launch result");
+        assert_eq!(launch,
+                   LaunchEmptyResult::AsyncJobId { async_job_id: "34g93hh34h04y".to_owned() });
+
+        let status: PollEmptyResult<PollError> = serde_json::from_str(r#"{".tag": "complete"}"#)
+            .expect("error deserializing job status");
+        assert_eq!(status, PollEmptyResult::Complete);
+        assert!(!status.is_in_progress());
+
+        let status: PollEmptyResult<PollError> = serde_json::from_str(r#"{".tag": "in_progress"}"#)
+            .expect("error deserializing job status");
+        assert!(status.is_in_progress());
+    }
+}
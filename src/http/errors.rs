@@ -21,6 +21,14 @@ error_chain!{
             description("An expected header wasn't found"),
             display("Couldn't find header: {}", header),
         }
+        ContentHashNotFound {
+            description("The response metadata doesn't contain a content hash"),
+            display("Couldn't find a content hash to verify the content against"),
+        }
+        AsyncJobTimeout(async_job_id: String) {
+            description("An async job was still in progress after the maximum number of polls"),
+            display("Async job {} is still in progress", async_job_id),
+        }
     }
 }
 
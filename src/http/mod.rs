@@ -10,9 +10,12 @@ use reqwest::header::{Headers, Authorization, Bearer, ContentType};
 use reqwest::Response as ReqwestResponse;
 
 use super::Dropbox;
+use content_hash::VerifyingReader;
 use self::errors::*;
-use self::errors::ErrorKind::HeaderNotFound;
+use self::errors::ErrorKind::{HeaderNotFound, ContentHashNotFound};
 
+pub mod async_job;
+pub mod pagination;
 pub mod errors;
 pub mod header {
     header! { (DropboxAPIArg, "Dropbox-API-Arg") => [String] }
@@ -48,6 +51,24 @@ impl<T> fmt::Debug for ContentResponse<T>
     }
 }
 
+impl<T> ContentResponse<T>
+    where T: HasContentHash
+{
+    /// Hashes the content while it is being read and fails the read reaching EOF with a
+    /// `content_hash::ContentHashMismatch` if it doesn't match the `content_hash` returned in the
+    /// `Dropbox-API-Result` header.
+    pub fn verify_content_hash(self) -> Result<ContentResponse<T>> {
+        let expected = match self.body.content_hash() {
+            Some(content_hash) => content_hash.to_owned(),
+            None => return Err(ContentHashNotFound.into()),
+        };
+        Ok(ContentResponse {
+            content: Box::new(VerifyingReader::new(self.content, &expected)),
+            ..self
+        })
+    }
+}
+
 impl<T> Read for ContentResponse<T> {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         self.content.read(buf)
@@ -135,6 +156,11 @@ impl<T, E> ContentResponseWithErr<T, E>
     }
 }
 
+/// Implemented by the metadata of downloaded content which carries a Dropbox content hash
+pub trait HasContentHash {
+    fn content_hash(&self) -> Option<&str>;
+}
+
 pub trait HasAccessToken {
     fn access_token(&self) -> &str;
 }
@@ -251,3 +277,53 @@ impl<C> ContentDownloadClient<ReqwestResponse> for C
         Ok(ContentResponseWithErr::try_from(res)?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use content_hash::ContentHashMismatch;
+
+    struct Metadata {
+        content_hash: Option<String>,
+    }
+
+    impl HasContentHash for Metadata {
+        fn content_hash(&self) -> Option<&str> {
+            self.content_hash.as_ref().map(|x| x.as_str())
+        }
+    }
+
+    fn content_response(content_hash: Option<&str>,
+                        content: &'static [u8])
+                        -> ContentResponse<Metadata> {
+        ContentResponse {
+            body: Metadata { content_hash: content_hash.map(|x| x.to_owned()) },
+            content: Box::new(content),
+            status: StatusCode::Ok,
+            headers: Headers::new(),
+        }
+    }
+
+    #[test]
+    fn test_verify_content_hash() {
+        let abc_hash = "4f8b42c22dd3729b519ba6f68d2da7cc5b2d606d05daed5ad5128cc03e6c6358";
+
+        let mut verified = content_response(Some(abc_hash), b"abc")
+            .verify_content_hash()
+            .expect("error verifying content hash");
+        let mut content = Vec::new();
+        verified.read_to_end(&mut content).expect("error reading verified content");
+        assert_eq!(content, b"abc");
+
+        let mut tampered = content_response(Some(abc_hash), b"abd")
+            .verify_content_hash()
+            .expect("error verifying content hash");
+        let error = tampered.read_to_end(&mut Vec::new()).unwrap_err();
+        assert!(ContentHashMismatch::from_io_error(&error).is_some());
+
+        match content_response(None, b"abc").verify_content_hash() {
+            Err(Error(ContentHashNotFound, _)) => {}
+            other => panic!("unexpected result {:?}", other.map(|r| r.body.content_hash)),
+        }
+    }
+}
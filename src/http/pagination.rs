@@ -0,0 +1,284 @@
+//! Helpers for the list endpoints returning a page of items with a `cursor` and `has_more`,
+//! whose following pages are fetched from a `/continue` endpoint.
+//!
+
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::io;
+
+use cursor_store::{CursorStore, StoredCursor};
+
+/// The maximum number of times a `PaginatedIter` restarts from the first page before
+/// giving up and yielding the error.
+const MAX_RESTARTS: u32 = 3;
+
+/// A page of items returned by a cursor-based list endpoint.
+#[derive(PartialEq,Eq,Debug,Clone)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub cursor: String,
+    /// The RFC 3339 timestamp after which the cursor expires, for the endpoints returning one.
+    pub cursor_expiration: Option<String>,
+    pub has_more: bool,
+}
+
+/// A cursor-based list endpoint and its `/continue` counterpart.
+pub trait Paginated {
+    type Item;
+    type Error;
+
+    fn first_page(&self) -> Result<Page<Self::Item>, Self::Error>;
+
+    fn next_page(&self, cursor: &str) -> Result<Page<Self::Item>, Self::Error>;
+
+    /// Whether `error`, returned by `next_page`, means the cursor can't be used anymore and the
+    /// listing should restart from the first page, e.g. when the cursor expired.
+    fn should_restart(&self, _error: &Self::Error) -> bool {
+        false
+    }
+
+    /// Identifies an item so the ones already yielded are skipped after a restart.
+    fn item_key(&self, item: &Self::Item) -> String;
+
+    /// Returns an iterator over every item, fetching the following pages as needed.
+    fn iter_all(self) -> PaginatedIter<Self>
+        where Self: Sized
+    {
+        PaginatedIter::new(self)
+    }
+}
+
+/// An iterator over the items of every page of a `Paginated` endpoint.
+///
+/// When the cursor stops being usable the listing restarts from the first page, skipping the
+/// items which were already yielded. Iteration stops after yielding the first other error.
+pub struct PaginatedIter<P: Paginated> {
+    pages: P,
+    items: VecDeque<P::Item>,
+    cursor: Option<StoredCursor>,
+    checkpoint: Option<(Box<CursorStore>, String)>,
+    started: bool,
+    /// Set once the last page was fetched.
+    finished: bool,
+    /// Set once an error was yielded, which stops the iteration but keeps the saved cursor.
+    errored: bool,
+    restarts: u32,
+    yielded: HashSet<String>,
+}
+
+impl<P: Paginated> PaginatedIter<P> {
+    pub fn new(pages: P) -> PaginatedIter<P> {
+        PaginatedIter {
+            pages: pages,
+            items: VecDeque::new(),
+            cursor: None,
+            checkpoint: None,
+            started: false,
+            finished: false,
+            errored: false,
+            restarts: 0,
+            yielded: HashSet::new(),
+        }
+    }
+
+    /// The cursor of the last page fetched, which can be passed to the `/continue` endpoint to
+    /// get the items added after the listing.
+    pub fn cursor(&self) -> Option<&str> {
+        self.cursor.as_ref().map(|x| x.value.as_str())
+    }
+
+    /// Resumes from the cursor saved in `store` under `key`, unless it expired, and saves the
+    /// cursor there each time the items of the previous one have all been yielded. The saved
+    /// cursor is removed once the listing completes, so the next one starts from the first page.
+    ///
+    /// Items of the page being iterated over when the process stops are yielded again on resume.
+    pub fn checkpoint<S>(mut self, store: S, key: &str) -> io::Result<PaginatedIter<P>>
+        where S: CursorStore + 'static
+    {
+        if let Some(cursor) = store.load_unexpired(key)? {
+            info!("[PaginatedIter] [key = {}] resuming from saved cursor", key);
+            self.cursor = Some(cursor);
+            self.started = true;
+        }
+        self.checkpoint = Some((Box::new(store), key.to_owned()));
+        Ok(self)
+    }
+
+    fn save_checkpoint(&self) -> io::Result<()> {
+        match (&self.checkpoint, &self.cursor) {
+            (&Some((ref store, ref key)), &Some(ref cursor)) => store.save(key, cursor),
+            _ => Ok(()),
+        }
+    }
+
+    fn remove_checkpoint(&mut self) -> io::Result<()> {
+        match self.checkpoint.take() {
+            Some((store, key)) => store.remove(&key),
+            None => Ok(()),
+        }
+    }
+
+    fn fetch(&mut self) -> Result<(), P::Error> {
+        let page = match self.cursor.clone() {
+            Some(ref cursor) if self.started => {
+                match self.pages.next_page(&cursor.value) {
+                    Ok(page) => page,
+                    Err(ref e) if self.restarts < MAX_RESTARTS && self.pages.should_restart(e) => {
+                        self.restarts += 1;
+                        self.pages.first_page()?
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+            _ => self.pages.first_page()?,
+        };
+        self.started = true;
+        self.finished = !page.has_more;
+        self.cursor = Some(StoredCursor {
+            value: page.cursor,
+            expiration: page.cursor_expiration,
+        });
+        self.items.extend(page.items);
+        Ok(())
+    }
+}
+
+impl<P> Iterator for PaginatedIter<P>
+    where P: Paginated,
+          P::Error: From<io::Error>
+{
+    type Item = Result<P::Item, P::Error>;
+
+    fn next(&mut self) -> Option<Result<P::Item, P::Error>> {
+        loop {
+            while let Some(item) = self.items.pop_front() {
+                if self.yielded.insert(self.pages.item_key(&item)) {
+                    return Some(Ok(item));
+                }
+            }
+            if self.errored {
+                return None;
+            }
+            let checkpoint = if self.finished {
+                self.remove_checkpoint()
+            } else {
+                self.save_checkpoint()
+            };
+            if let Err(e) = checkpoint {
+                self.errored = true;
+                self.checkpoint = None;
+                return Some(Err(e.into()));
+            }
+            if self.finished {
+                return None;
+            }
+            if let Err(e) = self.fetch() {
+                self.errored = true;
+                return Some(Err(e));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::io;
+
+    use cursor_store::{CursorStore, MemoryCursorStore, StoredCursor};
+    use super::*;
+
+    /// Pages of two numbers up to 6, whose cursor expires once after the second page.
+    struct Numbers {
+        expired: Cell<bool>,
+    }
+
+    impl Paginated for Numbers {
+        type Item = u32;
+        type Error = io::Error;
+
+        fn first_page(&self) -> io::Result<Page<u32>> {
+            self.next_page("0")
+        }
+
+        fn next_page(&self, cursor: &str) -> io::Result<Page<u32>> {
+            let start = cursor.parse::<u32>()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            if start == 4 && !self.expired.get() {
+                self.expired.set(true);
+                return Err(io::Error::new(io::ErrorKind::Other, "expired"));
+            }
+            Ok(Page {
+                items: vec![start + 1, start + 2],
+                cursor: (start + 2).to_string(),
+                cursor_expiration: None,
+                has_more: start + 2 < 6,
+            })
+        }
+
+        fn should_restart(&self, error: &io::Error) -> bool {
+            error.to_string() == "expired"
+        }
+
+        fn item_key(&self, item: &u32) -> String {
+            item.to_string()
+        }
+    }
+
+    #[test]
+    fn test_paginated_iter_restarts_without_duplicates() {
+        let numbers = Numbers { expired: Cell::new(false) };
+        let items = numbers.iter_all().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(items, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_paginated_iter_checkpoint() {
+        let store = MemoryCursorStore::new();
+        store.save("numbers", &StoredCursor::new("2", None)).unwrap();
+
+        let mut iter = Numbers { expired: Cell::new(true) }
+            .iter_all()
+            .checkpoint(store.clone(), "numbers")
+            .unwrap();
+        assert_eq!(iter.next().unwrap().unwrap(), 3);
+        assert_eq!(iter.next().unwrap().unwrap(), 4);
+        assert_eq!(store.load("numbers").unwrap().unwrap().value, "2");
+        assert_eq!(iter.next().unwrap().unwrap(), 5);
+        assert_eq!(store.load("numbers").unwrap().unwrap().value, "4");
+
+        assert_eq!(iter.collect::<Result<Vec<_>, _>>().unwrap(), vec![6]);
+        assert_eq!(store.load("numbers").unwrap(), None);
+    }
+
+    /// An endpoint which can't be reached.
+    struct Unavailable;
+
+    impl Paginated for Unavailable {
+        type Item = u32;
+        type Error = io::Error;
+
+        fn first_page(&self) -> io::Result<Page<u32>> {
+            self.next_page("0")
+        }
+
+        fn next_page(&self, _cursor: &str) -> io::Result<Page<u32>> {
+            Err(io::Error::new(io::ErrorKind::Other, "unavailable"))
+        }
+
+        fn item_key(&self, item: &u32) -> String {
+            item.to_string()
+        }
+    }
+
+    #[test]
+    fn test_paginated_iter_keeps_checkpoint_on_error() {
+        let store = MemoryCursorStore::new();
+        store.save("numbers", &StoredCursor::new("2", None)).unwrap();
+
+        let mut iter = Unavailable.iter_all().checkpoint(store.clone(), "numbers").unwrap();
+        assert!(iter.next().unwrap().is_err());
+        assert!(iter.next().is_none());
+        assert_eq!(store.load("numbers").unwrap().unwrap().value, "2");
+    }
+}
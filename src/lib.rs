@@ -12,19 +12,42 @@ extern crate serde_urlencoded;
 extern crate serde_derive;
 #[macro_use]
 extern crate error_chain;
+extern crate sha2;
+extern crate base64;
+extern crate zip;
+extern crate chrono;
 
 pub mod auth;
+pub mod content_hash;
+pub mod cursor_store;
+pub mod file_properties;
+pub mod file_requests;
+pub mod files;
 pub mod paper;
+pub mod sharing;
+pub mod team;
+pub mod users;
 pub mod http;
 
 use std::rc::Rc;
 
+use file_properties::FileProperties;
+use file_requests::FileRequests;
+use files::Files;
 use paper::Paper;
+use sharing::Sharing;
+use team::Team;
+use users::Users;
 
 #[derive(Clone)]
 pub struct Dropbox {
     access_token: Rc<String>,
+    file_properties: FileProperties,
+    file_requests: FileRequests,
+    files: Files,
     paper: Paper,
+    sharing: Sharing,
+    users: Users,
 }
 
 impl Dropbox {
@@ -32,11 +55,53 @@ impl Dropbox {
         let dropbox_access_token = Rc::new(access_token.to_owned());
         Dropbox {
             access_token: Rc::clone(&dropbox_access_token),
-            paper: Paper::new(dropbox_access_token),
+            file_properties: FileProperties::new(Rc::clone(&dropbox_access_token)),
+            file_requests: FileRequests::new(Rc::clone(&dropbox_access_token)),
+            files: Files::new(Rc::clone(&dropbox_access_token)),
+            paper: Paper::new(Rc::clone(&dropbox_access_token)),
+            sharing: Sharing::new(Rc::clone(&dropbox_access_token)),
+            users: Users::new(dropbox_access_token),
         }
     }
 
+    pub fn file_properties(&self) -> &FileProperties {
+        &self.file_properties
+    }
+
+    pub fn file_requests(&self) -> &FileRequests {
+        &self.file_requests
+    }
+
+    pub fn files(&self) -> &Files {
+        &self.files
+    }
+
     pub fn paper(&self) -> &Paper {
         &self.paper
     }
+
+    pub fn sharing(&self) -> &Sharing {
+        &self.sharing
+    }
+
+    pub fn users(&self) -> &Users {
+        &self.users
+    }
+}
+
+/// A client for the endpoints which act on a whole Dropbox Business team. Unlike `Dropbox`, it
+/// must be created with a team access token.
+#[derive(Clone)]
+pub struct DropboxTeam {
+    team: Team,
+}
+
+impl DropboxTeam {
+    pub fn new(team_access_token: &str) -> DropboxTeam {
+        DropboxTeam { team: Team::new(Rc::new(team_access_token.to_owned())) }
+    }
+
+    pub fn team(&self) -> &Team {
+        &self.team
+    }
 }
@@ -0,0 +1,145 @@
+use paper::errors::*;
+use paper::{Paper, PaperFolderMember, MAX_PAGE_LIMIT};
+use paper::users::{PaperDocMember, PaperDocPermissionLevel, UserOnPaperDocFilter};
+use users::BasicAccount;
+use users::account_cache::AccountCache;
+
+/// A user or invitee of a Paper doc or of its folder, with the details of their account.
+#[derive(PartialEq,Eq,Debug,Clone)]
+pub struct Collaborator {
+    /// `None` for invitees, who are only known by their email address.
+    pub account_id: Option<String>,
+    /// `None` for invitees and for the users whose account couldn't be found, e.g. because it
+    /// was deleted.
+    pub account: Option<BasicAccount>,
+    /// `None` for the users whose account couldn't be found.
+    pub email: Option<String>,
+    /// `None` for the users of the doc's folder and for the doc owner unless they are also listed
+    /// as a user of the doc.
+    pub permission_level: Option<PaperDocPermissionLevel>,
+    pub is_owner: bool,
+}
+
+impl Collaborator {
+    pub fn display_name(&self) -> Option<&str> {
+        self.account.as_ref().map(|x| x.name.display_name.as_str())
+    }
+
+    pub fn profile_photo_url(&self) -> Option<&str> {
+        self.account.as_ref().and_then(|x| x.profile_photo_url.as_ref()).map(|x| x.as_str())
+    }
+}
+
+/// A collaborator before its account is resolved.
+struct Pending {
+    account_id: Option<String>,
+    email: Option<String>,
+    permission_level: Option<PaperDocPermissionLevel>,
+    is_owner: bool,
+}
+
+fn resolve(client: &Paper,
+           cache: &AccountCache,
+           pending: Vec<Pending>)
+           -> Result<Vec<Collaborator>> {
+    let accounts = {
+        let account_ids = pending.iter()
+            .filter_map(|x| x.account_id.as_ref().map(|x| x.as_str()))
+            .collect::<Vec<_>>();
+        cache.resolve(client, &account_ids)?
+    };
+    Ok(pending.into_iter()
+        .map(|x| {
+            let account = x.account_id.as_ref().and_then(|x| accounts.get(x).cloned());
+            Collaborator {
+                email: x.email.or_else(|| account.as_ref().map(|x| x.email.clone())),
+                account_id: x.account_id,
+                account: account,
+                permission_level: x.permission_level,
+                is_owner: x.is_owner,
+            }
+        })
+        .collect())
+}
+
+/// Lists the doc owner, users and invitees of a Paper doc, resolving the accounts of the users
+/// through `cache` with as few get_account_batch calls as possible.
+pub fn users_list_with_accounts(client: &Paper,
+                                doc_id: &str,
+                                filter_by: Option<UserOnPaperDocFilter>,
+                                cache: &AccountCache)
+                                -> Result<Vec<Collaborator>> {
+    // The doc owner is only returned alongside a page of users, not by the iterator
+    let owner_id = client.users_list(doc_id).limit(1).send()?.body.doc_owner.account_id;
+
+    let mut builder = client.users_list(doc_id);
+    builder.limit(MAX_PAGE_LIMIT as i32);
+    if let Some(filter_by) = filter_by {
+        builder.filter_by(filter_by);
+    }
+    let mut pending = Vec::new();
+    let mut owner_listed = false;
+    for member in builder.iter() {
+        match member? {
+            PaperDocMember::User(user) => {
+                let is_owner = user.user.account_id == owner_id;
+                owner_listed = owner_listed || is_owner;
+                pending.push(Pending {
+                    account_id: Some(user.user.account_id),
+                    email: None,
+                    permission_level: Some(user.permission_level),
+                    is_owner: is_owner,
+                });
+            }
+            PaperDocMember::Invitee(invitee) => {
+                pending.push(Pending {
+                    account_id: None,
+                    email: Some(invitee.invitee.email),
+                    permission_level: Some(invitee.permission_level),
+                    is_owner: false,
+                });
+            }
+        }
+    }
+
+    if !owner_listed {
+        pending.insert(0,
+                       Pending {
+                           account_id: Some(owner_id),
+                           email: None,
+                           permission_level: None,
+                           is_owner: true,
+                       });
+    }
+    resolve(client, cache, pending)
+}
+
+/// Lists the users and invitees of the Paper folder containing a doc, resolving the accounts of
+/// the users through `cache`.
+pub fn list_folder_users_with_accounts(client: &Paper,
+                                       doc_id: &str,
+                                       cache: &AccountCache)
+                                       -> Result<Vec<Collaborator>> {
+    let mut pending = Vec::new();
+    for member in client.list_folder_users_all(doc_id) {
+        pending.push(match member? {
+            PaperFolderMember::User(user) => {
+                Pending {
+                    account_id: Some(user.account_id),
+                    email: None,
+                    permission_level: None,
+                    is_owner: false,
+                }
+            }
+            PaperFolderMember::Invitee(invitee) => {
+                Pending {
+                    account_id: None,
+                    email: Some(invitee.email),
+                    permission_level: None,
+                    is_owner: false,
+                }
+            }
+        });
+    }
+    resolve(client, cache, pending)
+}
@@ -4,6 +4,7 @@ use http::errors::APIError;
 error_chain!{
     links {
         Http(::http::errors::Error, ::http::errors::ErrorKind);
+        Users(::users::errors::Error, ::users::errors::ErrorKind);
     }
     foreign_links {
         Url(::reqwest::UrlError);
@@ -5,7 +5,9 @@
 //!
 
 pub mod users;
+pub mod collaborators;
 pub mod errors;
+pub mod pagination;
 
 use serde::{Serialize, Serializer};
 use reqwest::Url;
@@ -17,13 +19,19 @@ use self::errors::*;
 use http::{Response, ContentResponse};
 use http::{ResponseWithErr, ContentResponseWithErr};
 use http::{RPCClient, ContentDownloadClient, ContentUploadClient};
+use http::pagination::{Paginated, PaginatedIter};
 
 use self::users::{AddPaperDocUserRequestBuilder, UserOnPaperDocFilter, ListUsersOnPaperDocResponse,
-                  ListUsersOnPaperDocArgs, ListUsersOnPaperDocContinueArgs, RemovePaperDocUser,
-                  MemberSelector};
+                  ListUsersOnPaperDocRequestBuilder, RemovePaperDocUser, MemberSelector};
+use self::pagination::{ListPaperDocsPages, ListUsersOnPaperDocPages, ListUsersOnFolderPages};
+use self::collaborators::Collaborator;
+use users::account_cache::AccountCache;
 
 static BASE_URL: &'static str = "https://api.dropboxapi.com/2/paper/docs/";
 
+/// The largest page size accepted by the list endpoints, used by default by the list builders.
+const MAX_PAGE_LIMIT: usize = 1000;
+
 /// A client to interface with the endpoints in the Paper namespace of the Dropbox APIs
 #[derive(Debug,Clone)]
 pub struct Paper {
@@ -138,6 +146,27 @@ impl Paper {
         }
     }
 
+    /// Returns an iterator over all users and invitees on the Paper folder of the given doc,
+    /// following the cursors of docs/folder_users/list/continue and restarting if a cursor
+    /// expires.
+    pub fn list_folder_users_all(&self, doc_id: &str) -> PaginatedIter<ListUsersOnFolderPages> {
+        ListUsersOnFolderPages {
+                client: self.clone(),
+                doc_id: doc_id.to_owned(),
+                limit: MAX_PAGE_LIMIT as i32,
+            }
+            .iter_all()
+    }
+
+    /// Lists the users and invitees of the Paper folder containing the doc with the name, email
+    /// and profile photo of each user, fetched through `cache` in batches.
+    pub fn list_folder_users_with_accounts(&self,
+                                           doc_id: &str,
+                                           cache: &AccountCache)
+                                           -> Result<Vec<Collaborator>> {
+        collaborators::list_folder_users_with_accounts(self, doc_id, cache)
+    }
+
     /// Retrieves folder information for the given Paper doc. This includes:
     /// - folder sharing policy; permissions for subfolders are set by the top-level folder.
     /// - full 'filepath', i.e. the list of folders (both folderId and folderName) from the root folder to the folder directly containing the Paper doc.
@@ -157,46 +186,35 @@ impl Paper {
     /// Return the list of all Paper docs according to the argument specifications. To iterate over through the full pagination, pass the cursor to docs/list/continue.
     ///
     /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#paper-docs-list)
-    // TODO implement a builder for optional parameters?
-    pub fn list(&self,
-                filter_by: Option<ListPaperDocsFilterBy>,
-                sort_by: Option<ListPaperDocsSortBy>,
-                sort_order: Option<ListPaperDocsSortOrder>,
-                limit: usize)
-                -> Result<Response<ListPaperDocsResponse>> {
-        let url = Url::parse(BASE_URL)?
-            .join("list")?;
-
-        let resp_w_err: ResponseWithErr<_, ()> = self.rpc_request(url,
-                         &ListPaperDocsArgs {
-                             filter_by: filter_by,
-                             sort_by: sort_by,
-                             sort_order: sort_order,
-                             limit: limit,
-                         })?;
-        match resp_w_err {
-            ResponseWithErr::Ok(r) => Ok(r),
-            ResponseWithErr::Err(_) => {
-                unreachable!("paper: https://api.dropboxapi.com/2/paper/docs/list should not \
-                              return errors")
-            }
-        }
+    pub fn list(&self) -> ListPaperDocsRequestBuilder<Paper> {
+        ListPaperDocsRequestBuilder::new(self)
     }
 
     /// Once a cursor has been retrieved from docs/list, use this to paginate through all Paper doc.
     ///
     /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#paper-docs-list-continue)
     pub fn list_continue(&self, cursor: &str) -> Result<Response<ListPaperDocsResponse>> {
-        let url = Url::parse(BASE_URL)?
-            .join("list/")?
-            .join("continue")?;
+        list_docs_continue(self, cursor)
+    }
 
-        let resp_w_err = self.rpc_request(url,
-                         &ListPaperDocsContinueArgs { cursor: cursor.to_owned() })?;
-        match resp_w_err {
-            ResponseWithErr::Ok(r) => Ok(r),
-            ResponseWithErr::Err(e) => Err(ErrorKind::ListDocsCursorErr(e).into()),
+    /// Returns an iterator over the ids of all Paper docs, following the cursors of
+    /// docs/list/continue and restarting if a cursor expires or is reset.
+    pub fn list_all(&self,
+                    filter_by: Option<ListPaperDocsFilterBy>,
+                    sort_by: Option<ListPaperDocsSortBy>,
+                    sort_order: Option<ListPaperDocsSortOrder>)
+                    -> PaginatedIter<ListPaperDocsPages<Paper>> {
+        let mut builder = self.list();
+        if let Some(filter_by) = filter_by {
+            builder.filter_by(filter_by);
+        }
+        if let Some(sort_by) = sort_by {
+            builder.sort_by(sort_by);
+        }
+        if let Some(sort_order) = sort_order {
+            builder.sort_order(sort_order);
         }
+        builder.iter()
     }
 
     /// Permanently deletes the given Paper doc. This operation is final as the doc cannot be recovered.
@@ -297,22 +315,8 @@ impl Paper {
     /// The list will include both users, the explicitly shared ones as well as those who came in using the Paper url link.
     ///
     /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#paper-docs-users-list)
-    pub fn users_list(&self,
-                      doc_id: &str,
-                      limit: i32,
-                      filter_by: UserOnPaperDocFilter)
-                      -> Result<Response<ListUsersOnPaperDocResponse>> {
-        let url = Url::parse(BASE_URL)?.join("users/list")?;
-        let resp_w_err = self.rpc_request(url,
-                         &ListUsersOnPaperDocArgs {
-                             doc_id: doc_id.to_owned(),
-                             limit: limit,
-                             filter_by: filter_by,
-                         })?;
-        match resp_w_err {
-            ResponseWithErr::Ok(r) => Ok(r),
-            ResponseWithErr::Err(e) => Err(ErrorKind::DocLookupErr(e).into()),
-        }
+    pub fn users_list(&self, doc_id: &str) -> ListUsersOnPaperDocRequestBuilder<Paper> {
+        ListUsersOnPaperDocRequestBuilder::new(self, doc_id)
     }
 
     /// Once a cursor has been retrieved from docs/users/list, use this to paginate through all users on the Paper doc.
@@ -322,16 +326,26 @@ impl Paper {
                                doc_id: &str,
                                cursor: &str)
                                -> Result<Response<ListUsersOnPaperDocResponse>> {
-        let url = Url::parse(BASE_URL)?.join("users/list/continue")?;
-        let resp_w_err = self.rpc_request(url,
-                         &ListUsersOnPaperDocContinueArgs {
-                             doc_id: doc_id.to_owned(),
-                             cursor: cursor.to_owned(),
-                         })?;
-        match resp_w_err {
-            ResponseWithErr::Ok(r) => Ok(r),
-            ResponseWithErr::Err(e) => Err(ErrorKind::ListUsersCursorErr(e).into()),
-        }
+        users::users_list_continue(self, doc_id, cursor)
+    }
+
+    /// Returns an iterator over all users and invitees on the Paper doc, following the cursors of
+    /// docs/users/list/continue and restarting if a cursor expires or is reset.
+    pub fn users_list_all(&self,
+                          doc_id: &str,
+                          filter_by: UserOnPaperDocFilter)
+                          -> PaginatedIter<ListUsersOnPaperDocPages<Paper>> {
+        self.users_list(doc_id).filter_by(filter_by).iter()
+    }
+
+    /// Lists the owner, users and invitees of the Paper doc along with their permission level and
+    /// the name, email and profile photo of each user, fetched through `cache` in batches.
+    pub fn users_list_with_accounts(&self,
+                                    doc_id: &str,
+                                    filter_by: Option<UserOnPaperDocFilter>,
+                                    cache: &AccountCache)
+                                    -> Result<Vec<Collaborator>> {
+        collaborators::users_list_with_accounts(self, doc_id, filter_by, cache)
     }
 
     /// Allows an owner or editor to remove users from a Paper doc using their email address or Dropbox account ID.
@@ -441,6 +455,13 @@ pub struct InviteeInfo {
     pub email: String,
 }
 
+/// A user or a pending invitee of a Paper folder, as yielded by the folder user list iterators.
+#[derive(Debug,Clone)]
+pub enum PaperFolderMember {
+    User(UserInfo),
+    Invitee(InviteeInfo),
+}
+
 #[derive(Debug,Clone,Serialize,Deserialize)]
 pub struct UserInfo {
     pub account_id: String,
@@ -496,7 +517,7 @@ pub enum ListPaperDocsSortOrder {
     Descending,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ListPaperDocsArgs {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub filter_by: Option<ListPaperDocsFilterBy>,
@@ -525,6 +546,97 @@ pub struct ListPaperDocsContinueArgs {
     pub cursor: String,
 }
 
+pub struct ListPaperDocsRequestBuilder<T> {
+    client: T,
+    args: ListPaperDocsArgs,
+}
+
+impl<T> ListPaperDocsRequestBuilder<T>
+    where T: RPCClient + Clone
+{
+    /// Defaults to pages of 1000 doc ids, the most Dropbox returns at once.
+    pub fn new(client: &T) -> ListPaperDocsRequestBuilder<T> {
+        ListPaperDocsRequestBuilder {
+            client: client.clone(),
+            args: ListPaperDocsArgs {
+                filter_by: None,
+                sort_by: None,
+                sort_order: None,
+                limit: MAX_PAGE_LIMIT,
+            },
+        }
+    }
+
+    pub fn filter_by(&mut self,
+                     filter_by: ListPaperDocsFilterBy)
+                     -> &mut ListPaperDocsRequestBuilder<T> {
+        self.args.filter_by = Some(filter_by);
+        self
+    }
+
+    pub fn sort_by(&mut self, sort_by: ListPaperDocsSortBy) -> &mut ListPaperDocsRequestBuilder<T> {
+        self.args.sort_by = Some(sort_by);
+        self
+    }
+
+    pub fn sort_order(&mut self,
+                      sort_order: ListPaperDocsSortOrder)
+                      -> &mut ListPaperDocsRequestBuilder<T> {
+        self.args.sort_order = Some(sort_order);
+        self
+    }
+
+    /// The maximum number of doc ids per page, up to 1000.
+    pub fn limit(&mut self, limit: usize) -> &mut ListPaperDocsRequestBuilder<T> {
+        self.args.limit = limit;
+        self
+    }
+
+    pub fn send(&self) -> Result<Response<ListPaperDocsResponse>> {
+        list_docs(&self.client, &self.args)
+    }
+
+    /// Returns an iterator over every doc id, fetching the following pages as needed.
+    pub fn iter(&self) -> PaginatedIter<ListPaperDocsPages<T>> {
+        ListPaperDocsPages {
+                client: self.client.clone(),
+                args: self.args.clone(),
+            }
+            .iter_all()
+    }
+}
+
+pub fn list_docs<T: RPCClient>(client: &T,
+                               args: &ListPaperDocsArgs)
+                               -> Result<Response<ListPaperDocsResponse>> {
+    let url = Url::parse(BASE_URL)?
+        .join("list")?;
+
+    let resp_w_err: ResponseWithErr<_, ()> = client.rpc_request(url, args)?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(_) => {
+            unreachable!("paper: https://api.dropboxapi.com/2/paper/docs/list should not \
+                          return errors")
+        }
+    }
+}
+
+pub fn list_docs_continue<T: RPCClient>(client: &T,
+                                        cursor: &str)
+                                        -> Result<Response<ListPaperDocsResponse>> {
+    let url = Url::parse(BASE_URL)?
+        .join("list/")?
+        .join("continue")?;
+
+    let resp_w_err = client.rpc_request(url,
+                                        &ListPaperDocsContinueArgs { cursor: cursor.to_owned() })?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::ListDocsCursorErr(e).into()),
+    }
+}
+
 /**
  * Sharing Policy
  **/
@@ -0,0 +1,216 @@
+use http::pagination::{Page, Paginated};
+use paper::errors::*;
+use paper::errors::ErrorKind::{ListDocsCursorErr, ListUsersCursorErr};
+use http::RPCClient;
+use paper::{Paper, ListPaperDocsArgs, ListUsersOnFolderResponse, PaperFolderMember};
+use paper::users::{ListUsersOnPaperDocArgs, ListUsersOnPaperDocResponse, PaperDocMember};
+
+fn is_restartable(cursor_error: PaperApiCursorError) -> bool {
+    match cursor_error {
+        PaperApiCursorError::ExpiredCursor |
+        PaperApiCursorError::Reset => true,
+        PaperApiCursorError::InvalidCursor |
+        PaperApiCursorError::WrongUserInCursor => false,
+    }
+}
+
+/**
+ * docs/list
+ **/
+/// The pages of doc ids returned by docs/list and docs/list/continue.
+pub struct ListPaperDocsPages<T> {
+    pub client: T,
+    pub args: ListPaperDocsArgs,
+}
+
+impl<T: RPCClient> Paginated for ListPaperDocsPages<T> {
+    type Item = String;
+    type Error = Error;
+
+    fn first_page(&self) -> Result<Page<String>> {
+        let resp = ::paper::list_docs(&self.client, &self.args)?;
+        Ok(Page {
+            items: resp.body.doc_ids,
+            cursor: resp.body.cursor.value,
+            cursor_expiration: Some(resp.body.cursor.expiration),
+            has_more: resp.body.has_more,
+        })
+    }
+
+    fn next_page(&self, cursor: &str) -> Result<Page<String>> {
+        let resp = ::paper::list_docs_continue(&self.client, cursor)?;
+        Ok(Page {
+            items: resp.body.doc_ids,
+            cursor: resp.body.cursor.value,
+            cursor_expiration: Some(resp.body.cursor.expiration),
+            has_more: resp.body.has_more,
+        })
+    }
+
+    fn should_restart(&self, error: &Error) -> bool {
+        match *error.kind() {
+            ListDocsCursorErr(ref e) => {
+                match e.error {
+                    ListDocsCursorError::CursorError { cursor_error } => {
+                        is_restartable(cursor_error)
+                    }
+                }
+            }
+            _ => false,
+        }
+    }
+
+    fn item_key(&self, doc_id: &String) -> String {
+        doc_id.clone()
+    }
+}
+
+/**
+ * docs/users/list
+ **/
+/// The pages of users returned by docs/users/list and docs/users/list/continue.
+pub struct ListUsersOnPaperDocPages<T> {
+    pub client: T,
+    pub args: ListUsersOnPaperDocArgs,
+}
+
+impl<T: RPCClient> Paginated for ListUsersOnPaperDocPages<T> {
+    type Item = PaperDocMember;
+    type Error = Error;
+
+    fn first_page(&self) -> Result<Page<PaperDocMember>> {
+        let resp = ::paper::users::users_list(&self.client, &self.args)?;
+        Ok(doc_users_page(resp.body))
+    }
+
+    fn next_page(&self, cursor: &str) -> Result<Page<PaperDocMember>> {
+        let resp = ::paper::users::users_list_continue(&self.client, &self.args.doc_id, cursor)?;
+        Ok(doc_users_page(resp.body))
+    }
+
+    fn should_restart(&self, error: &Error) -> bool {
+        is_restartable_users_error(error)
+    }
+
+    fn item_key(&self, member: &PaperDocMember) -> String {
+        match *member {
+            PaperDocMember::User(ref user) => user.user.account_id.clone(),
+            PaperDocMember::Invitee(ref invitee) => invitee_key(&invitee.invitee.email),
+        }
+    }
+}
+
+fn doc_users_page(resp: ListUsersOnPaperDocResponse) -> Page<PaperDocMember> {
+    let items = resp.users
+        .into_iter()
+        .map(PaperDocMember::User)
+        .chain(resp.invitees.into_iter().map(PaperDocMember::Invitee))
+        .collect();
+    Page {
+        items: items,
+        cursor: resp.cursor.value,
+        cursor_expiration: Some(resp.cursor.expiration),
+        has_more: resp.has_more,
+    }
+}
+
+/**
+ * docs/folder_users/list
+ **/
+/// The pages of users returned by docs/folder_users/list and docs/folder_users/list/continue.
+pub struct ListUsersOnFolderPages {
+    pub client: Paper,
+    pub doc_id: String,
+    pub limit: i32,
+}
+
+impl Paginated for ListUsersOnFolderPages {
+    type Item = PaperFolderMember;
+    type Error = Error;
+
+    fn first_page(&self) -> Result<Page<PaperFolderMember>> {
+        let resp = self.client.list_folder_users(&self.doc_id, self.limit)?;
+        Ok(folder_users_page(resp.body))
+    }
+
+    fn next_page(&self, cursor: &str) -> Result<Page<PaperFolderMember>> {
+        let resp = self.client.list_folder_users_continue(&self.doc_id, cursor)?;
+        Ok(folder_users_page(resp.body))
+    }
+
+    fn should_restart(&self, error: &Error) -> bool {
+        is_restartable_users_error(error)
+    }
+
+    fn item_key(&self, member: &PaperFolderMember) -> String {
+        match *member {
+            PaperFolderMember::User(ref user) => user.account_id.clone(),
+            PaperFolderMember::Invitee(ref invitee) => invitee_key(&invitee.email),
+        }
+    }
+}
+
+fn folder_users_page(resp: ListUsersOnFolderResponse) -> Page<PaperFolderMember> {
+    let items = resp.users
+        .into_iter()
+        .map(PaperFolderMember::User)
+        .chain(resp.invitees.into_iter().map(PaperFolderMember::Invitee))
+        .collect();
+    Page {
+        items: items,
+        cursor: resp.cursor.value,
+        cursor_expiration: Some(resp.cursor.expiration),
+        has_more: resp.has_more,
+    }
+}
+
+// Invitees have no account id, their key is prefixed so it can't collide with one.
+fn invitee_key(email: &str) -> String {
+    format!("invitee:{}", email)
+}
+
+fn is_restartable_users_error(error: &Error) -> bool {
+    match *error.kind() {
+        ListUsersCursorErr(ref e) => {
+            match e.error {
+                ListUsersCursorError::CursorError { cursor_error } => is_restartable(cursor_error),
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use super::*;
+
+    #[test]
+    fn test_doc_users_page_keeps_invitees() {
+        let resp: ListUsersOnPaperDocResponse = serde_json::from_str(r#"{
+            "invitees": [{
+                "invitee": {".tag": "email", "email": "jessica@example.com"},
+                "permission_level": {".tag": "edit"}
+            }],
+            "users": [{
+                "user": {"account_id": "dbid:AAH4f99T0taONIb", "same_team": true},
+                "permission_level": {".tag": "view_and_comment"}
+            }],
+            "doc_owner": {"account_id": "dbid:AAH4f99T0taONIb", "same_team": true},
+            "cursor": {"value": "zxcvbnm", "expiration": "2017-11-30T03:40:00Z"},
+            "has_more": false
+        }"#)
+            .expect("error deserializing users list");
+
+        let page = doc_users_page(resp);
+        assert_eq!(page.items.len(), 2);
+        match page.items[1] {
+            PaperDocMember::Invitee(ref invitee) => {
+                assert_eq!(invitee.invitee.email, "jessica@example.com")
+            }
+            ref member => panic!("unexpected member {:?}", member),
+        }
+    }
+}
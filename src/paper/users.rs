@@ -6,6 +6,8 @@ use reqwest::Url;
 use paper::errors::*;
 use http::{Response, ResponseWithErr};
 use http::RPCClient;
+use http::pagination::{Paginated, PaginatedIter};
+use paper::pagination::ListUsersOnPaperDocPages;
 
 /**
  * add users
@@ -141,7 +143,8 @@ pub enum UserOnPaperDocFilter {
 pub struct ListUsersOnPaperDocArgs {
     pub doc_id: String,
     pub limit: i32,
-    pub filter_by: UserOnPaperDocFilter,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter_by: Option<UserOnPaperDocFilter>,
 }
 #[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
 pub struct InviteeInfo {
@@ -163,6 +166,12 @@ pub struct UserInfoWithPermissionLevel {
     pub user: UserInfo,
     pub permission_level: PaperDocPermissionLevel,
 }
+/// A user or a pending invitee of a Paper doc, as yielded by the user list iterators.
+#[derive(PartialEq,Eq,Debug,Clone)]
+pub enum PaperDocMember {
+    User(UserInfoWithPermissionLevel),
+    Invitee(InviteeInfoWithPermissionLevel),
+}
 #[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
 pub struct ListUsersOnPaperDocResponse {
     pub invitees: Vec<InviteeInfoWithPermissionLevel>,
@@ -178,6 +187,82 @@ pub struct ListUsersOnPaperDocContinueArgs {
     pub cursor: String,
 }
 
+pub struct ListUsersOnPaperDocRequestBuilder<T> {
+    client: T,
+    args: ListUsersOnPaperDocArgs,
+}
+
+impl<T> ListUsersOnPaperDocRequestBuilder<T>
+    where T: RPCClient + Clone
+{
+    /// Defaults to pages of 1000 users, the most Dropbox returns at once.
+    pub fn new(client: &T, doc_id: &str) -> ListUsersOnPaperDocRequestBuilder<T> {
+        ListUsersOnPaperDocRequestBuilder {
+            client: client.clone(),
+            args: ListUsersOnPaperDocArgs {
+                doc_id: doc_id.to_owned(),
+                limit: super::MAX_PAGE_LIMIT as i32,
+                filter_by: None,
+            },
+        }
+    }
+
+    /// Lists only the users who visited the doc or only those it was shared with. Dropbox
+    /// defaults to the users who visited it.
+    pub fn filter_by(&mut self,
+                     filter_by: UserOnPaperDocFilter)
+                     -> &mut ListUsersOnPaperDocRequestBuilder<T> {
+        self.args.filter_by = Some(filter_by);
+        self
+    }
+
+    /// The maximum number of users per page, up to 1000.
+    pub fn limit(&mut self, limit: i32) -> &mut ListUsersOnPaperDocRequestBuilder<T> {
+        self.args.limit = limit;
+        self
+    }
+
+    pub fn send(&self) -> Result<Response<ListUsersOnPaperDocResponse>> {
+        users_list(&self.client, &self.args)
+    }
+
+    /// Returns an iterator over every user, fetching the following pages as needed.
+    pub fn iter(&self) -> PaginatedIter<ListUsersOnPaperDocPages<T>> {
+        ListUsersOnPaperDocPages {
+                client: self.client.clone(),
+                args: self.args.clone(),
+            }
+            .iter_all()
+    }
+}
+
+pub fn users_list<T: RPCClient>(client: &T,
+                                args: &ListUsersOnPaperDocArgs)
+                                -> Result<Response<ListUsersOnPaperDocResponse>> {
+    let url = Url::parse(super::BASE_URL)?.join("users/list")?;
+    let resp_w_err = client.rpc_request(url, args)?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::DocLookupErr(e).into()),
+    }
+}
+
+pub fn users_list_continue<T: RPCClient>(client: &T,
+                                         doc_id: &str,
+                                         cursor: &str)
+                                         -> Result<Response<ListUsersOnPaperDocResponse>> {
+    let url = Url::parse(super::BASE_URL)?.join("users/list/continue")?;
+    let resp_w_err = client.rpc_request(url,
+                     &ListUsersOnPaperDocContinueArgs {
+                         doc_id: doc_id.to_owned(),
+                         cursor: cursor.to_owned(),
+                     })?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::ListUsersCursorErr(e).into()),
+    }
+}
+
 /**
  * remove
  **/
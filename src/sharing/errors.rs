@@ -0,0 +1,676 @@
+use http::errors::APIError;
+use files::errors::LookupError;
+use http::async_job::PollError;
+use sharing::links::SharedLinkMetadata;
+use sharing::folders::SharedFolderMetadata;
+use sharing::members::MemberAccessLevelResult;
+
+error_chain!{
+    links {
+        Http(::http::errors::Error, ::http::errors::ErrorKind);
+        Files(::files::errors::Error, ::files::errors::ErrorKind);
+    }
+    foreign_links {
+        Url(::reqwest::UrlError);
+        Reqwest(::reqwest::Error);
+        Utf8(::std::string::FromUtf8Error);
+        Io(::std::io::Error);
+        Json(::serde_json::Error);
+        UrlEncodedSer(::serde_urlencoded::ser::Error);
+    }
+    errors {
+        CreateSharedLinkWithSettingsErr(error: APIError<CreateSharedLinkWithSettingsError>) {
+            description("CreateSharedLinkWithSettingsError"),
+            display("{:?}", error)
+        }
+        ListSharedLinksErr(error: APIError<ListSharedLinksError>) {
+            description("ListSharedLinksError"),
+            display("{:?}", error)
+        }
+        ModifySharedLinkSettingsErr(error: APIError<ModifySharedLinkSettingsError>) {
+            description("ModifySharedLinkSettingsError"),
+            display("{:?}", error)
+        }
+        RevokeSharedLinkErr(error: APIError<RevokeSharedLinkError>) {
+            description("RevokeSharedLinkError"),
+            display("{:?}", error)
+        }
+        GetSharedLinkMetadataErr(error: APIError<SharedLinkError>) {
+            description("SharedLinkError"),
+            display("{:?}", error)
+        }
+        GetSharedLinkFileErr(error: APIError<GetSharedLinkFileError>) {
+            description("GetSharedLinkFileError"),
+            display("{:?}", error)
+        }
+        ShareFolderErr(error: APIError<ShareFolderError>) {
+            description("ShareFolderError"),
+            display("{:?}", error)
+        }
+        MountFolderErr(error: APIError<MountFolderError>) {
+            description("MountFolderError"),
+            display("{:?}", error)
+        }
+        UnmountFolderErr(error: APIError<UnmountFolderError>) {
+            description("UnmountFolderError"),
+            display("{:?}", error)
+        }
+        UnshareFolderErr(error: APIError<UnshareFolderError>) {
+            description("UnshareFolderError"),
+            display("{:?}", error)
+        }
+        RelinquishFolderMembershipErr(error: APIError<RelinquishFolderMembershipError>) {
+            description("RelinquishFolderMembershipError"),
+            display("{:?}", error)
+        }
+        TransferFolderErr(error: APIError<TransferFolderError>) {
+            description("TransferFolderError"),
+            display("{:?}", error)
+        }
+        UpdateFolderPolicyErr(error: APIError<UpdateFolderPolicyError>) {
+            description("UpdateFolderPolicyError"),
+            display("{:?}", error)
+        }
+        GetFolderMetadataErr(error: APIError<SharedFolderAccessError>) {
+            description("SharedFolderAccessError"),
+            display("{:?}", error)
+        }
+        ListFoldersContinueErr(error: APIError<ListFoldersContinueError>) {
+            description("ListFoldersContinueError"),
+            display("{:?}", error)
+        }
+        PollErr(error: APIError<PollError>) {
+            description("PollError"),
+            display("{:?}", error)
+        }
+        ShareFolderFailed(error: ShareFolderError) {
+            description("ShareFolderError"),
+            display("{:?}", error)
+        }
+        SharingJobFailed(error: JobError) {
+            description("JobError"),
+            display("{:?}", error)
+        }
+        ListFolderMembersErr(error: APIError<SharedFolderAccessError>) {
+            description("SharedFolderAccessError"),
+            display("{:?}", error)
+        }
+        AddFolderMemberErr(error: APIError<AddFolderMemberError>) {
+            description("AddFolderMemberError"),
+            display("{:?}", error)
+        }
+        RemoveFolderMemberErr(error: APIError<RemoveFolderMemberError>) {
+            description("RemoveFolderMemberError"),
+            display("{:?}", error)
+        }
+        UpdateFolderMemberErr(error: APIError<UpdateFolderMemberError>) {
+            description("UpdateFolderMemberError"),
+            display("{:?}", error)
+        }
+        ListFolderMembersContinueErr(error: APIError<ListFolderMembersContinueError>) {
+            description("ListFolderMembersContinueError"),
+            display("{:?}", error)
+        }
+        AddFileMemberErr(error: APIError<AddFileMemberError>) {
+            description("AddFileMemberError"),
+            display("{:?}", error)
+        }
+        RemoveFileMemberErr(error: APIError<RemoveFileMemberError>) {
+            description("RemoveFileMemberError"),
+            display("{:?}", error)
+        }
+        ListFileMembersErr(error: APIError<ListFileMembersError>) {
+            description("ListFileMembersError"),
+            display("{:?}", error)
+        }
+        ListFileMembersContinueErr(error: APIError<ListFileMembersContinueError>) {
+            description("ListFileMembersContinueError"),
+            display("{:?}", error)
+        }
+        ListFileMembersBatchErr(error: APIError<SharingUserError>) {
+            description("SharingUserError"),
+            display("{:?}", error)
+        }
+        RemoveFolderMemberFailed(error: RemoveFolderMemberError) {
+            description("RemoveFolderMemberError"),
+            display("{:?}", error)
+        }
+        UnsafeSharedLinkEntryName(name: String) {
+            description("A shared link entry name can't be used as a local file name"),
+            display("Refusing to write the shared link entry {:?} to disk", name)
+        }
+        SharedLinkNotFound(path: String) {
+            description("A shared link already exists but couldn't be found"),
+            display("Couldn't find the existing shared link of {}", path)
+        }
+    }
+}
+
+impl From<APIError<CreateSharedLinkWithSettingsError>> for ErrorKind {
+    fn from(error: APIError<CreateSharedLinkWithSettingsError>) -> Self {
+        ErrorKind::CreateSharedLinkWithSettingsErr(error)
+    }
+}
+
+impl From<APIError<ListSharedLinksError>> for ErrorKind {
+    fn from(error: APIError<ListSharedLinksError>) -> Self {
+        ErrorKind::ListSharedLinksErr(error)
+    }
+}
+
+impl From<APIError<ModifySharedLinkSettingsError>> for ErrorKind {
+    fn from(error: APIError<ModifySharedLinkSettingsError>) -> Self {
+        ErrorKind::ModifySharedLinkSettingsErr(error)
+    }
+}
+
+impl From<APIError<RevokeSharedLinkError>> for ErrorKind {
+    fn from(error: APIError<RevokeSharedLinkError>) -> Self {
+        ErrorKind::RevokeSharedLinkErr(error)
+    }
+}
+
+impl From<APIError<SharedLinkError>> for ErrorKind {
+    fn from(error: APIError<SharedLinkError>) -> Self {
+        ErrorKind::GetSharedLinkMetadataErr(error)
+    }
+}
+
+impl From<APIError<GetSharedLinkFileError>> for ErrorKind {
+    fn from(error: APIError<GetSharedLinkFileError>) -> Self {
+        ErrorKind::GetSharedLinkFileErr(error)
+    }
+}
+
+impl From<APIError<ShareFolderError>> for ErrorKind {
+    fn from(error: APIError<ShareFolderError>) -> Self {
+        ErrorKind::ShareFolderErr(error)
+    }
+}
+
+impl From<APIError<MountFolderError>> for ErrorKind {
+    fn from(error: APIError<MountFolderError>) -> Self {
+        ErrorKind::MountFolderErr(error)
+    }
+}
+
+impl From<APIError<UnmountFolderError>> for ErrorKind {
+    fn from(error: APIError<UnmountFolderError>) -> Self {
+        ErrorKind::UnmountFolderErr(error)
+    }
+}
+
+impl From<APIError<UnshareFolderError>> for ErrorKind {
+    fn from(error: APIError<UnshareFolderError>) -> Self {
+        ErrorKind::UnshareFolderErr(error)
+    }
+}
+
+impl From<APIError<RelinquishFolderMembershipError>> for ErrorKind {
+    fn from(error: APIError<RelinquishFolderMembershipError>) -> Self {
+        ErrorKind::RelinquishFolderMembershipErr(error)
+    }
+}
+
+impl From<APIError<TransferFolderError>> for ErrorKind {
+    fn from(error: APIError<TransferFolderError>) -> Self {
+        ErrorKind::TransferFolderErr(error)
+    }
+}
+
+impl From<APIError<UpdateFolderPolicyError>> for ErrorKind {
+    fn from(error: APIError<UpdateFolderPolicyError>) -> Self {
+        ErrorKind::UpdateFolderPolicyErr(error)
+    }
+}
+
+impl From<APIError<SharedFolderAccessError>> for ErrorKind {
+    fn from(error: APIError<SharedFolderAccessError>) -> Self {
+        ErrorKind::GetFolderMetadataErr(error)
+    }
+}
+
+impl From<APIError<ListFoldersContinueError>> for ErrorKind {
+    fn from(error: APIError<ListFoldersContinueError>) -> Self {
+        ErrorKind::ListFoldersContinueErr(error)
+    }
+}
+
+impl From<APIError<PollError>> for ErrorKind {
+    fn from(error: APIError<PollError>) -> Self {
+        ErrorKind::PollErr(error)
+    }
+}
+
+impl From<APIError<AddFolderMemberError>> for ErrorKind {
+    fn from(error: APIError<AddFolderMemberError>) -> Self {
+        ErrorKind::AddFolderMemberErr(error)
+    }
+}
+
+impl From<APIError<RemoveFolderMemberError>> for ErrorKind {
+    fn from(error: APIError<RemoveFolderMemberError>) -> Self {
+        ErrorKind::RemoveFolderMemberErr(error)
+    }
+}
+
+impl From<APIError<UpdateFolderMemberError>> for ErrorKind {
+    fn from(error: APIError<UpdateFolderMemberError>) -> Self {
+        ErrorKind::UpdateFolderMemberErr(error)
+    }
+}
+
+impl From<APIError<ListFolderMembersContinueError>> for ErrorKind {
+    fn from(error: APIError<ListFolderMembersContinueError>) -> Self {
+        ErrorKind::ListFolderMembersContinueErr(error)
+    }
+}
+
+impl From<APIError<AddFileMemberError>> for ErrorKind {
+    fn from(error: APIError<AddFileMemberError>) -> Self {
+        ErrorKind::AddFileMemberErr(error)
+    }
+}
+
+impl From<APIError<RemoveFileMemberError>> for ErrorKind {
+    fn from(error: APIError<RemoveFileMemberError>) -> Self {
+        ErrorKind::RemoveFileMemberErr(error)
+    }
+}
+
+impl From<APIError<ListFileMembersError>> for ErrorKind {
+    fn from(error: APIError<ListFileMembersError>) -> Self {
+        ErrorKind::ListFileMembersErr(error)
+    }
+}
+
+impl From<APIError<ListFileMembersContinueError>> for ErrorKind {
+    fn from(error: APIError<ListFileMembersContinueError>) -> Self {
+        ErrorKind::ListFileMembersContinueErr(error)
+    }
+}
+
+impl From<APIError<SharingUserError>> for ErrorKind {
+    fn from(error: APIError<SharingUserError>) -> Self {
+        ErrorKind::ListFileMembersBatchErr(error)
+    }
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum SharedLinkSettingsError {
+    /// The given settings are invalid, e.g. an expiration in the past or an empty password.
+    InvalidSettings,
+    /// The user is not allowed to use some of the given settings.
+    NotAuthorized,
+}
+
+#[derive(Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum SharedLinkAlreadyExistsMetadata {
+    Metadata { metadata: SharedLinkMetadata },
+}
+
+#[derive(Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum CreateSharedLinkWithSettingsError {
+    Path { path: LookupError },
+    EmailNotVerified,
+    /// The metadata of the existing link is only returned if the settings requested match its
+    /// settings.
+    SharedLinkAlreadyExists {
+        shared_link_already_exists: Option<SharedLinkAlreadyExistsMetadata>,
+    },
+    SettingsError { settings_error: SharedLinkSettingsError },
+    AccessDenied,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum ListSharedLinksError {
+    Path { path: LookupError },
+    /// The cursor is no longer valid and the listing must be started again.
+    Reset,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum ModifySharedLinkSettingsError {
+    SharedLinkNotFound,
+    SharedLinkAccessDenied,
+    UnsupportedLinkType,
+    SettingsError { settings_error: SharedLinkSettingsError },
+    EmailNotVerified,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum RevokeSharedLinkError {
+    SharedLinkNotFound,
+    SharedLinkAccessDenied,
+    UnsupportedLinkType,
+    SharedLinkMalformed,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum SharedLinkError {
+    SharedLinkNotFound,
+    /// The link is protected by a password or restricted to a team the user isn't part of.
+    SharedLinkAccessDenied,
+    UnsupportedLinkType,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum GetSharedLinkFileError {
+    SharedLinkNotFound,
+    SharedLinkAccessDenied,
+    UnsupportedLinkType,
+    /// Directories can't be downloaded, only the files inside them.
+    SharedLinkIsDirectory,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum SharedFolderAccessError {
+    InvalidId,
+    NotAMember,
+    EmailUnverified,
+    /// The folder is not mounted in the user's Dropbox.
+    Unmounted,
+}
+
+#[derive(Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum SharePathError {
+    IsFile,
+    InsideSharedFolder,
+    ContainsSharedFolder,
+    ContainsAppFolder,
+    ContainsTeamFolder,
+    IsAppFolder,
+    InsideAppFolder,
+    IsPublicFolder,
+    InsidePublicFolder,
+    /// The folder is already shared, with the given metadata.
+    AlreadyShared(SharedFolderMetadata),
+    InvalidPath,
+    IsOsxPackage,
+    InsideOsxPackage,
+    IsVault,
+    IsVaultLocked,
+    IsFamily,
+}
+
+#[derive(Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum ShareFolderError {
+    EmailUnverified,
+    BadPath { bad_path: SharePathError },
+    TeamPolicyDisallowsMemberPolicy,
+    DisallowedSharedLinkPolicy,
+    NoPermission,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+pub struct InsufficientQuotaAmounts {
+    pub space_needed: u64,
+    pub space_shortage: u64,
+    pub space_left: u64,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum MountFolderError {
+    AccessError { access_error: SharedFolderAccessError },
+    InsideSharedFolder,
+    InsufficientQuota(InsufficientQuotaAmounts),
+    AlreadyMounted,
+    NoPermission,
+    NotMountable,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum UnmountFolderError {
+    AccessError { access_error: SharedFolderAccessError },
+    NoPermission,
+    /// Folders shared with a group or with the whole team can't be unmounted.
+    NotUnmountable,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum UnshareFolderError {
+    AccessError { access_error: SharedFolderAccessError },
+    TeamFolder,
+    NoPermission,
+    TooManyFiles,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum RelinquishFolderMembershipError {
+    AccessError { access_error: SharedFolderAccessError },
+    /// The owner must transfer the folder or unshare it instead.
+    FolderOwner,
+    Mounted,
+    GroupAccess,
+    TeamFolder,
+    NoPermission,
+    NoExplicitAccess,
+}
+
+/// The error of a failed unshare_folder, remove_folder_member or relinquish_folder_membership
+/// job.
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum JobError {
+    UnshareFolderError { unshare_folder_error: UnshareFolderError },
+    RemoveFolderMemberError { remove_folder_member_error: RemoveFolderMemberError },
+    RelinquishFolderMembershipError {
+        relinquish_folder_membership_error: RelinquishFolderMembershipError,
+    },
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum TransferFolderError {
+    AccessError { access_error: SharedFolderAccessError },
+    InvalidDropboxId,
+    NewOwnerNotAMember,
+    NewOwnerUnmounted,
+    NewOwnerEmailUnverified,
+    TeamFolder,
+    NoPermission,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum UpdateFolderPolicyError {
+    AccessError { access_error: SharedFolderAccessError },
+    NotOnTeam,
+    TeamPolicyDisallowsMemberPolicy,
+    DisallowedSharedLinkPolicy,
+    NoPermission,
+    TeamFolder,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum ListFoldersContinueError {
+    InvalidCursor,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum AddMemberSelectorError {
+    AutomaticGroup,
+    InvalidDropboxId { invalid_dropbox_id: String },
+    InvalidEmail { invalid_email: String },
+    UnverifiedDropboxId { unverified_dropbox_id: String },
+    GroupDeleted,
+    GroupNotOnTeam,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum AddFolderMemberError {
+    AccessError { access_error: SharedFolderAccessError },
+    EmailUnverified,
+    BannedMember,
+    BadMember { bad_member: AddMemberSelectorError },
+    CantShareOutsideTeam,
+    /// The folder would have more than the given maximum number of members.
+    TooManyMembers { too_many_members: u64 },
+    TooManyPendingInvites { too_many_pending_invites: u64 },
+    RateLimit,
+    TooManyInvitees,
+    InsufficientPlan,
+    TeamFolder,
+    NoPermission,
+    InvalidSharedFolder,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum SharedFolderMemberError {
+    InvalidDropboxId,
+    NotAMember,
+    /// The member only has access through a parent folder or a group.
+    NoExplicitAccess(MemberAccessLevelResult),
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum RemoveFolderMemberError {
+    AccessError { access_error: SharedFolderAccessError },
+    MemberError { member_error: SharedFolderMemberError },
+    FolderOwner,
+    GroupAccess,
+    TeamFolder,
+    NoPermission,
+    TooManyFiles,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum UpdateFolderMemberError {
+    AccessError { access_error: SharedFolderAccessError },
+    MemberError { member_error: SharedFolderMemberError },
+    /// The member only has access through a group, adding it explicitly failed.
+    NoExplicitAccess { no_explicit_access: AddFolderMemberError },
+    InsufficientPlan,
+    NoPermission,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum ListFolderMembersContinueError {
+    AccessError { access_error: SharedFolderAccessError },
+    InvalidCursor,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum SharingUserError {
+    EmailUnverified,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum SharingFileAccessError {
+    NoPermission,
+    InvalidFile,
+    IsFolder,
+    InsidePublicFolder,
+    InsideOsxPackage,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum FileMemberActionError {
+    InvalidMember,
+    NoPermission,
+    AccessError { access_error: SharingFileAccessError },
+    NoExplicitAccess(MemberAccessLevelResult),
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum AddFileMemberError {
+    UserError { user_error: SharingUserError },
+    AccessError { access_error: SharingFileAccessError },
+    RateLimit,
+    InvalidComment,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum RemoveFileMemberError {
+    UserError { user_error: SharingUserError },
+    AccessError { access_error: SharingFileAccessError },
+    NoExplicitAccess(MemberAccessLevelResult),
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum ListFileMembersError {
+    UserError { user_error: SharingUserError },
+    AccessError { access_error: SharingFileAccessError },
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum ListFileMembersContinueError {
+    UserError { user_error: SharingUserError },
+    AccessError { access_error: SharingFileAccessError },
+    InvalidCursor,
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::StatusCode;
+
+    use super::*;
+
+    #[test]
+    fn test_shared_link_already_exists_deserialization() {
+        let error_json = r#"{
+    "error_summary": "shared_link_already_exists/metadata/...",
+    "error": {
+        ".tag": "shared_link_already_exists",
+        "shared_link_already_exists": {
+            ".tag": "metadata",
+            "metadata": {
+                ".tag": "file",
+                "url": "https://www.dropbox.com/s/2sn712vy1ovegw8/Prime_Numbers.txt?dl=0",
+                "name": "Prime_Numbers.txt",
+                "link_permissions": {
+                    "can_revoke": false,
+                    "resolved_visibility": {".tag": "public"}
+                },
+                "client_modified": "2015-05-12T15:50:38Z",
+                "server_modified": "2015-05-12T15:50:38Z",
+                "rev": "a1c10ce0dd78",
+                "size": 7212,
+                "id": "id:a4ayc_80_OEAAAAAAAAAXw",
+                "path_lower": "/homework/math/prime_numbers.txt"
+            }
+        }
+    }
+}"#;
+        let error: APIError<CreateSharedLinkWithSettingsError> =
+            APIError::build_error(StatusCode::Conflict, error_json.to_owned())
+                .expect("error deserializing shared_link_already_exists");
+        match error.error {
+            CreateSharedLinkWithSettingsError::SharedLinkAlreadyExists {
+                shared_link_already_exists: Some(SharedLinkAlreadyExistsMetadata::Metadata {
+                    metadata: SharedLinkMetadata::File(ref file)
+                })
+            } => assert_eq!(file.path_lower, Some("/homework/math/prime_numbers.txt".to_owned())),
+            ref e => panic!("unexpected error {:?}", e),
+        }
+    }
+}
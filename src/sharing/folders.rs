@@ -0,0 +1,501 @@
+use reqwest::Url;
+
+use std::time::Duration;
+
+use sharing::errors::*;
+use sharing::errors::ErrorKind::ShareFolderErr;
+use http::{Response, ResponseWithErr};
+use http::RPCClient;
+use http::async_job::{AsyncJob, AsyncJobStatus, LaunchEmptyResult, LaunchResult, PollEmptyResult,
+                      PollError, PollOptions};
+use http::pagination::{Page, Paginated, PaginatedIter};
+
+/// The most shared folders Dropbox returns per page.
+pub const MAX_LIST_FOLDERS_LIMIT: u32 = 1000;
+
+/**
+ * policies and access levels
+ **/
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum AccessLevel {
+    Owner,
+    Editor,
+    Viewer,
+    ViewerNoComment,
+    Traverse,
+    NoAccess,
+    Other,
+}
+
+/// Who can be a member of a shared folder.
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum MemberPolicy {
+    Team,
+    Anyone,
+    Other,
+}
+
+/// Who can add and remove members of a shared folder.
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum AclUpdatePolicy {
+    Owner,
+    Editors,
+    Other,
+}
+
+/// Who can view the shared links to a shared folder and its contents.
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum SharedLinkPolicy {
+    Anyone,
+    Team,
+    Members,
+    Other,
+}
+
+/// Whether the members can see who viewed the files of a shared folder.
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum ViewerInfoPolicy {
+    Enabled,
+    Disabled,
+    Other,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct FolderPolicy {
+    pub acl_update_policy: AclUpdatePolicy,
+    pub shared_link_policy: SharedLinkPolicy,
+    /// Only set when the folder is owned by a team.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_policy: Option<MemberPolicy>,
+    /// The member policy in effect, taking the team policies into account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolved_member_policy: Option<MemberPolicy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub viewer_info_policy: Option<ViewerInfoPolicy>,
+}
+
+/**
+ * SharedFolderMetadata
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct Team {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct SharedFolderMetadata {
+    /// The access level of the current user.
+    pub access_type: AccessLevel,
+    pub is_inside_team_folder: bool,
+    pub is_team_folder: bool,
+    pub name: String,
+    pub policy: FolderPolicy,
+    pub preview_url: String,
+    pub shared_folder_id: String,
+    pub time_invited: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_display_names: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_team: Option<Team>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_shared_folder_id: Option<String>,
+    /// Not set when the folder isn't mounted in the user's Dropbox.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path_lower: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path_display: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_folder_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub folder_id: Option<String>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct SharedFolderArg {
+    pub shared_folder_id: String,
+}
+
+/**
+ * share_folder
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ShareFolderArg {
+    pub path: String,
+    pub force_async: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acl_update_policy: Option<AclUpdatePolicy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_policy: Option<MemberPolicy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shared_link_policy: Option<SharedLinkPolicy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub viewer_info_policy: Option<ViewerInfoPolicy>,
+}
+
+pub struct ShareFolderRequestBuilder<T> {
+    client: T,
+    arg: ShareFolderArg,
+    poll_options: PollOptions,
+}
+
+impl<T> ShareFolderRequestBuilder<T>
+    where T: RPCClient + Clone
+{
+    pub fn new(client: &T, path: &str) -> ShareFolderRequestBuilder<T> {
+        ShareFolderRequestBuilder {
+            client: client.clone(),
+            arg: ShareFolderArg {
+                path: path.to_owned(),
+                force_async: false,
+                acl_update_policy: None,
+                member_policy: None,
+                shared_link_policy: None,
+                viewer_info_policy: None,
+            },
+            poll_options: PollOptions::default(),
+        }
+    }
+
+    pub fn acl_update_policy(&mut self,
+                             acl_update_policy: AclUpdatePolicy)
+                             -> &mut ShareFolderRequestBuilder<T> {
+        self.arg.acl_update_policy = Some(acl_update_policy);
+        self
+    }
+
+    pub fn member_policy(&mut self,
+                         member_policy: MemberPolicy)
+                         -> &mut ShareFolderRequestBuilder<T> {
+        self.arg.member_policy = Some(member_policy);
+        self
+    }
+
+    pub fn shared_link_policy(&mut self,
+                              shared_link_policy: SharedLinkPolicy)
+                              -> &mut ShareFolderRequestBuilder<T> {
+        self.arg.shared_link_policy = Some(shared_link_policy);
+        self
+    }
+
+    pub fn viewer_info_policy(&mut self,
+                              viewer_info_policy: ViewerInfoPolicy)
+                              -> &mut ShareFolderRequestBuilder<T> {
+        self.arg.viewer_info_policy = Some(viewer_info_policy);
+        self
+    }
+
+    /// Always shares the folder through an async job, even when it could be done right away.
+    pub fn force_async(&mut self, force_async: bool) -> &mut ShareFolderRequestBuilder<T> {
+        self.arg.force_async = force_async;
+        self
+    }
+
+    pub fn poll_interval(&mut self, interval: Duration) -> &mut ShareFolderRequestBuilder<T> {
+        self.poll_options.interval = interval;
+        self
+    }
+
+    pub fn max_polls(&mut self, max_polls: u32) -> &mut ShareFolderRequestBuilder<T> {
+        self.poll_options.max_polls = Some(max_polls);
+        self
+    }
+
+    /// Shares the folder and blocks until Dropbox finished sharing it, returning the metadata of
+    /// the new shared folder.
+    pub fn send(&self) -> Result<SharedFolderMetadata> {
+        let url = Url::parse(super::BASE_URL)?.join("share_folder")?;
+        let resp_w_err: ResponseWithErr<LaunchResult<SharedFolderMetadata>, _> =
+            self.client.rpc_request(url, &self.arg)?;
+        let launch = match resp_w_err {
+            ResponseWithErr::Ok(r) => r.body,
+            ResponseWithErr::Err(e) => return Err(ShareFolderErr(e).into()),
+        };
+
+        match launch {
+            LaunchResult::Complete(metadata) => Ok(metadata),
+            LaunchResult::AsyncJobId { async_job_id } => {
+                let check_url = Url::parse(super::BASE_URL)?.join("check_share_job_status")?;
+                let status: ResponseWithErr<AsyncJobStatus<SharedFolderMetadata,
+                                                           ShareFolderError>,
+                                            PollError> =
+                    AsyncJob::new(&self.client, check_url, &async_job_id)
+                        .options(self.poll_options)
+                        .wait()?;
+                match status {
+                    ResponseWithErr::Ok(Response { body: AsyncJobStatus::Complete(metadata), .. }) => {
+                        Ok(metadata)
+                    }
+                    ResponseWithErr::Ok(Response { body: AsyncJobStatus::Failed { failed }, .. }) => {
+                        Err(ErrorKind::ShareFolderFailed(failed).into())
+                    }
+                    ResponseWithErr::Ok(_) => unreachable!("sharing: share_folder job in progress"),
+                    ResponseWithErr::Err(e) => Err(ErrorKind::PollErr(e).into()),
+                }
+            }
+        }
+    }
+}
+
+/**
+ * unshare_folder and relinquish_folder_membership
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct UnshareFolderArg {
+    pub shared_folder_id: String,
+    /// Keeps a copy of the folder's contents in the Dropbox of the current members.
+    pub leave_a_copy: bool,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct RelinquishFolderMembershipArg {
+    pub shared_folder_id: String,
+    /// Keeps a copy of the folder's contents in the user's Dropbox.
+    pub leave_a_copy: bool,
+}
+
+/// Waits for the completion of the jobs launched by the endpoints which return a
+/// `LaunchEmptyResult`, checked through `check_job_status`.
+pub fn wait_for_job<T: RPCClient + Clone>(client: &T, launch: LaunchEmptyResult) -> Result<()> {
+    let async_job_id = match launch {
+        LaunchEmptyResult::Complete => return Ok(()),
+        LaunchEmptyResult::AsyncJobId { async_job_id } => async_job_id,
+    };
+    let check_url = Url::parse(super::BASE_URL)?.join("check_job_status")?;
+    let status: ResponseWithErr<PollEmptyResult<JobError>, PollError> =
+        AsyncJob::new(client, check_url, &async_job_id).wait()?;
+    match status {
+        ResponseWithErr::Ok(Response { body: PollEmptyResult::Complete, .. }) => Ok(()),
+        ResponseWithErr::Ok(Response { body: PollEmptyResult::Failed { failed }, .. }) => {
+            Err(ErrorKind::SharingJobFailed(failed).into())
+        }
+        ResponseWithErr::Ok(_) => unreachable!("sharing: job in progress"),
+        ResponseWithErr::Err(e) => Err(ErrorKind::PollErr(e).into()),
+    }
+}
+
+/**
+ * transfer_folder
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct TransferFolderArg {
+    pub shared_folder_id: String,
+    pub to_dropbox_id: String,
+}
+
+/**
+ * update_folder_policy
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct UpdateFolderPolicyArg {
+    pub shared_folder_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_policy: Option<MemberPolicy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acl_update_policy: Option<AclUpdatePolicy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub viewer_info_policy: Option<ViewerInfoPolicy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shared_link_policy: Option<SharedLinkPolicy>,
+}
+
+pub struct UpdateFolderPolicyRequestBuilder<T> {
+    client: T,
+    arg: UpdateFolderPolicyArg,
+}
+
+impl<T> UpdateFolderPolicyRequestBuilder<T>
+    where T: RPCClient + Clone
+{
+    /// Policies which aren't set are left unchanged.
+    pub fn new(client: &T, shared_folder_id: &str) -> UpdateFolderPolicyRequestBuilder<T> {
+        UpdateFolderPolicyRequestBuilder {
+            client: client.clone(),
+            arg: UpdateFolderPolicyArg {
+                shared_folder_id: shared_folder_id.to_owned(),
+                member_policy: None,
+                acl_update_policy: None,
+                viewer_info_policy: None,
+                shared_link_policy: None,
+            },
+        }
+    }
+
+    pub fn member_policy(&mut self,
+                         member_policy: MemberPolicy)
+                         -> &mut UpdateFolderPolicyRequestBuilder<T> {
+        self.arg.member_policy = Some(member_policy);
+        self
+    }
+
+    pub fn acl_update_policy(&mut self,
+                             acl_update_policy: AclUpdatePolicy)
+                             -> &mut UpdateFolderPolicyRequestBuilder<T> {
+        self.arg.acl_update_policy = Some(acl_update_policy);
+        self
+    }
+
+    pub fn viewer_info_policy(&mut self,
+                              viewer_info_policy: ViewerInfoPolicy)
+                              -> &mut UpdateFolderPolicyRequestBuilder<T> {
+        self.arg.viewer_info_policy = Some(viewer_info_policy);
+        self
+    }
+
+    pub fn shared_link_policy(&mut self,
+                              shared_link_policy: SharedLinkPolicy)
+                              -> &mut UpdateFolderPolicyRequestBuilder<T> {
+        self.arg.shared_link_policy = Some(shared_link_policy);
+        self
+    }
+
+    pub fn send(&self) -> Result<Response<SharedFolderMetadata>> {
+        let url = Url::parse(super::BASE_URL)?.join("update_folder_policy")?;
+        let resp_w_err = self.client.rpc_request(url, &self.arg)?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::UpdateFolderPolicyErr(e).into()),
+        }
+    }
+}
+
+/**
+ * list_folders and list_mountable_folders
+ **/
+/// The shared folders which can be listed.
+#[derive(PartialEq,Eq,Debug,Copy,Clone)]
+pub enum FolderListing {
+    /// The shared folders the user is a member of.
+    Joined,
+    /// The shared folders the user can mount or unmount.
+    Mountable,
+}
+
+impl FolderListing {
+    fn endpoint(&self) -> &'static str {
+        match *self {
+            FolderListing::Joined => "list_folders",
+            FolderListing::Mountable => "list_mountable_folders",
+        }
+    }
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ListFoldersArgs {
+    pub limit: u32,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ListFoldersContinueArg {
+    pub cursor: String,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ListFoldersResult {
+    pub entries: Vec<SharedFolderMetadata>,
+    /// Only set when there are more folders to list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+pub struct ListFoldersRequestBuilder<T> {
+    client: T,
+    listing: FolderListing,
+    arg: ListFoldersArgs,
+}
+
+impl<T> ListFoldersRequestBuilder<T>
+    where T: RPCClient + Clone
+{
+    /// Defaults to pages of 1000 folders, the most Dropbox returns at once.
+    pub fn new(client: &T, listing: FolderListing) -> ListFoldersRequestBuilder<T> {
+        ListFoldersRequestBuilder {
+            client: client.clone(),
+            listing: listing,
+            arg: ListFoldersArgs { limit: MAX_LIST_FOLDERS_LIMIT },
+        }
+    }
+
+    /// The maximum number of folders per page, up to 1000.
+    pub fn limit(&mut self, limit: u32) -> &mut ListFoldersRequestBuilder<T> {
+        self.arg.limit = limit;
+        self
+    }
+
+    pub fn send(&self) -> Result<Response<ListFoldersResult>> {
+        list_folders(&self.client, self.listing, &self.arg)
+    }
+
+    /// Returns an iterator over every folder, fetching the following pages as needed.
+    pub fn iter(&self) -> PaginatedIter<ListFoldersPages<T>> {
+        ListFoldersPages {
+                client: self.client.clone(),
+                listing: self.listing,
+                arg: self.arg.clone(),
+            }
+            .iter_all()
+    }
+}
+
+/// The pages of shared folders returned by list_folders or list_mountable_folders.
+pub struct ListFoldersPages<T> {
+    pub client: T,
+    pub listing: FolderListing,
+    pub arg: ListFoldersArgs,
+}
+
+impl<T: RPCClient> Paginated for ListFoldersPages<T> {
+    type Item = SharedFolderMetadata;
+    type Error = Error;
+
+    fn first_page(&self) -> Result<Page<SharedFolderMetadata>> {
+        Ok(folders_page(list_folders(&self.client, self.listing, &self.arg)?.body))
+    }
+
+    fn next_page(&self, cursor: &str) -> Result<Page<SharedFolderMetadata>> {
+        Ok(folders_page(list_folders_continue(&self.client, self.listing, cursor)?.body))
+    }
+
+    fn item_key(&self, folder: &SharedFolderMetadata) -> String {
+        folder.shared_folder_id.clone()
+    }
+}
+
+fn folders_page(result: ListFoldersResult) -> Page<SharedFolderMetadata> {
+    Page {
+        items: result.entries,
+        has_more: result.cursor.is_some(),
+        cursor: result.cursor.unwrap_or_default(),
+        cursor_expiration: None,
+    }
+}
+
+pub fn list_folders<T: RPCClient>(client: &T,
+                                  listing: FolderListing,
+                                  arg: &ListFoldersArgs)
+                                  -> Result<Response<ListFoldersResult>> {
+    let url = Url::parse(super::BASE_URL)?.join(listing.endpoint())?;
+    let resp_w_err: ResponseWithErr<_, ()> = client.rpc_request(url, arg)?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(_) => unreachable!("sharing: list_folders has no error"),
+    }
+}
+
+pub fn list_folders_continue<T: RPCClient>(client: &T,
+                                           listing: FolderListing,
+                                           cursor: &str)
+                                           -> Result<Response<ListFoldersResult>> {
+    let url = Url::parse(super::BASE_URL)?.join(&format!("{}/continue", listing.endpoint()))?;
+    let resp_w_err = client.rpc_request(url,
+                     &ListFoldersContinueArg { cursor: cursor.to_owned() })?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::ListFoldersContinueErr(e).into()),
+    }
+}
@@ -0,0 +1,146 @@
+use reqwest::Url;
+use reqwest::Response as ReqwestResponse;
+
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use sharing::errors::*;
+use sharing::links::SharedLinkMetadata;
+use files::Metadata;
+use files::list_folder::{self, ListFolderArg, SharedLink};
+use http::{Response, ResponseWithErr, ContentResponse, ContentResponseWithErr};
+use http::{RPCClient, ContentDownloadClient};
+
+/**
+ * get_shared_link_metadata and get_shared_link_file
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct GetSharedLinkMetadataArg {
+    pub url: String,
+    /// A path relative to the root of a shared folder link, starting with a slash.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_password: Option<String>,
+}
+
+pub fn get_shared_link_metadata<T: RPCClient>(client: &T,
+                                              arg: &GetSharedLinkMetadataArg)
+                                              -> Result<Response<SharedLinkMetadata>> {
+    let url = Url::parse(super::BASE_URL)?.join("get_shared_link_metadata")?;
+    let resp_w_err = client.rpc_request(url, arg)?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::GetSharedLinkMetadataErr(e).into()),
+    }
+}
+
+pub fn get_shared_link_file<T>(client: &T,
+                               arg: &GetSharedLinkMetadataArg)
+                               -> Result<ContentResponse<SharedLinkMetadata>>
+    where T: ContentDownloadClient<ReqwestResponse>
+{
+    let url = Url::parse(super::CONTENT_BASE_URL)?.join("get_shared_link_file")?;
+    let resp_w_err = client.content_download(url, arg)?;
+    match resp_w_err {
+        ContentResponseWithErr::Ok(r) => Ok(r),
+        ContentResponseWithErr::Err(e) => Err(ErrorKind::GetSharedLinkFileErr(e).into()),
+    }
+}
+
+/// Downloads the file or the whole folder behind the shared link `url` into `destination`,
+/// keeping the folder structure of the link. Returns the paths of the downloaded files.
+///
+/// Folders are listed one at a time since Dropbox can't list shared links recursively, and the
+/// files are streamed to disk one by one.
+pub fn mirror_shared_link<T, P>(client: &T,
+                                url: &str,
+                                link_password: Option<&str>,
+                                destination: P)
+                                -> Result<Vec<PathBuf>>
+    where T: RPCClient + ContentDownloadClient<ReqwestResponse>,
+          P: AsRef<Path>
+{
+    let destination = destination.as_ref();
+    fs::create_dir_all(destination)?;
+    let link_file = |path: Option<String>| {
+        GetSharedLinkMetadataArg {
+            url: url.to_owned(),
+            path: path,
+            link_password: link_password.map(|x| x.to_owned()),
+        }
+    };
+
+    if let SharedLinkMetadata::File(metadata) = get_shared_link_metadata(client, &link_file(None))?
+        .body {
+        let local_path = destination.join(local_name(&metadata.name)?);
+        download(client, &link_file(None), &local_path)?;
+        return Ok(vec![local_path]);
+    }
+
+    let mut mirrored = Vec::new();
+    // The remote folders left to list, relative to the root of the link, with their local path.
+    let mut folders = vec![(String::new(), destination.to_owned())];
+    while let Some((folder, local_folder)) = folders.pop() {
+        let mut arg = ListFolderArg::new(&folder);
+        arg.shared_link = Some(SharedLink {
+            url: url.to_owned(),
+            password: link_password.map(|x| x.to_owned()),
+        });
+        let mut page = list_folder::list_folder(client, &arg)?.body;
+        loop {
+            for entry in page.entries {
+                let path = format!("{}/{}", folder, entry.name());
+                let local_path = local_folder.join(local_name(entry.name())?);
+                match entry {
+                    Metadata::Folder(_) => {
+                        fs::create_dir_all(&local_path)?;
+                        folders.push((path, local_path));
+                    }
+                    Metadata::File(_) => {
+                        download(client, &link_file(Some(path)), &local_path)?;
+                        mirrored.push(local_path);
+                    }
+                    Metadata::Deleted(_) => {}
+                }
+            }
+            if !page.has_more {
+                break;
+            }
+            page = list_folder::list_folder_continue(client, &page.cursor)?.body;
+        }
+    }
+    Ok(mirrored)
+}
+
+fn download<T>(client: &T, arg: &GetSharedLinkMetadataArg, local_path: &Path) -> Result<()>
+    where T: ContentDownloadClient<ReqwestResponse>
+{
+    let mut download_resp = get_shared_link_file(client, arg)?;
+    let mut file = File::create(local_path)?;
+    io::copy(&mut download_resp, &mut file)?;
+    Ok(())
+}
+
+/// Rejects the names which would be written outside of the destination folder.
+fn local_name(name: &str) -> Result<&str> {
+    if name.is_empty() || name == "." || name == ".." || name.contains(|c| c == '/' || c == '\\') {
+        return Err(ErrorKind::UnsafeSharedLinkEntryName(name.to_owned()).into());
+    }
+    Ok(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::local_name;
+
+    #[test]
+    fn test_local_name() {
+        assert_eq!(local_name("report.pdf").unwrap(), "report.pdf");
+        assert_eq!(local_name("..data").unwrap(), "..data");
+        for name in &["", ".", "..", "a/b", "..\\b"] {
+            assert!(local_name(name).is_err(), "{:?} should be rejected", name);
+        }
+    }
+}
@@ -0,0 +1,439 @@
+use reqwest::Url;
+
+use sharing::errors::*;
+use sharing::errors::ErrorKind::{CreateSharedLinkWithSettingsErr, ListSharedLinksErr,
+                                 SharedLinkNotFound};
+use http::{Response, ResponseWithErr};
+use http::RPCClient;
+use http::pagination::{Page, Paginated, PaginatedIter};
+
+/**
+ * shared link settings
+ **/
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum RequestedVisibility {
+    Public,
+    TeamOnly,
+    Password,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum ResolvedVisibility {
+    Public,
+    TeamOnly,
+    Password,
+    TeamAndPassword,
+    SharedFolderOnly,
+    NoOne,
+    OnlyYou,
+    Other,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum LinkAudience {
+    Public,
+    Team,
+    NoOne,
+    Password,
+    Members,
+    Other,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum RequestedLinkAccessLevel {
+    Viewer,
+    Editor,
+    Max,
+    Default,
+    Other,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum LinkAccessLevel {
+    Viewer,
+    Editor,
+    Other,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum SharedLinkAccessFailureReason {
+    LoginRequired,
+    EmailVerifyRequired,
+    PasswordRequired,
+    TeamOnly,
+    OwnerOnly,
+    Other,
+}
+
+/// The settings of a shared link. Fields left to `None` keep the Dropbox defaults.
+#[derive(PartialEq,Eq,Debug,Clone,Default,Serialize,Deserialize)]
+pub struct SharedLinkSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested_visibility: Option<RequestedVisibility>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_password: Option<String>,
+    /// Formatted as "%Y-%m-%dT%H:%M:%SZ".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audience: Option<LinkAudience>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access: Option<RequestedLinkAccessLevel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_download: Option<bool>,
+}
+
+/**
+ * shared link metadata
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct LinkPermissions {
+    pub can_revoke: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolved_visibility: Option<ResolvedVisibility>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested_visibility: Option<RequestedVisibility>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revoke_failure_reason: Option<SharedLinkAccessFailureReason>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective_audience: Option<LinkAudience>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_access_level: Option<LinkAccessLevel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_download: Option<bool>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct FileLinkMetadata {
+    pub url: String,
+    pub name: String,
+    pub link_permissions: LinkPermissions,
+    pub client_modified: String,
+    pub server_modified: String,
+    pub rev: String,
+    pub size: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path_lower: Option<String>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct FolderLinkMetadata {
+    pub url: String,
+    pub name: String,
+    pub link_permissions: LinkPermissions,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path_lower: Option<String>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum SharedLinkMetadata {
+    File(FileLinkMetadata),
+    Folder(FolderLinkMetadata),
+}
+
+impl SharedLinkMetadata {
+    pub fn url(&self) -> &str {
+        match *self {
+            SharedLinkMetadata::File(ref metadata) => &metadata.url,
+            SharedLinkMetadata::Folder(ref metadata) => &metadata.url,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match *self {
+            SharedLinkMetadata::File(ref metadata) => &metadata.name,
+            SharedLinkMetadata::Folder(ref metadata) => &metadata.name,
+        }
+    }
+
+    pub fn path_lower(&self) -> Option<&str> {
+        match *self {
+            SharedLinkMetadata::File(ref metadata) => metadata.path_lower.as_ref(),
+            SharedLinkMetadata::Folder(ref metadata) => metadata.path_lower.as_ref(),
+        }
+        .map(|x| x.as_str())
+    }
+
+    pub fn expires(&self) -> Option<&str> {
+        match *self {
+            SharedLinkMetadata::File(ref metadata) => metadata.expires.as_ref(),
+            SharedLinkMetadata::Folder(ref metadata) => metadata.expires.as_ref(),
+        }
+        .map(|x| x.as_str())
+    }
+
+    pub fn link_permissions(&self) -> &LinkPermissions {
+        match *self {
+            SharedLinkMetadata::File(ref metadata) => &metadata.link_permissions,
+            SharedLinkMetadata::Folder(ref metadata) => &metadata.link_permissions,
+        }
+    }
+}
+
+/**
+ * create_shared_link_with_settings
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct CreateSharedLinkWithSettingsArg {
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<SharedLinkSettings>,
+}
+
+pub struct CreateSharedLinkRequestBuilder<T> {
+    client: T,
+    path: String,
+    settings: SharedLinkSettings,
+}
+
+impl<T> CreateSharedLinkRequestBuilder<T>
+    where T: RPCClient + Clone
+{
+    pub fn new(client: &T, path: &str) -> CreateSharedLinkRequestBuilder<T> {
+        CreateSharedLinkRequestBuilder {
+            client: client.clone(),
+            path: path.to_owned(),
+            settings: SharedLinkSettings::default(),
+        }
+    }
+
+    /// Protects the link with a password. Needs a Dropbox Professional or Business account.
+    pub fn password(&mut self, password: &str) -> &mut CreateSharedLinkRequestBuilder<T> {
+        self.settings.requested_visibility = Some(RequestedVisibility::Password);
+        self.settings.link_password = Some(password.to_owned());
+        self
+    }
+
+    /// Expires the link at `expires`, formatted as "%Y-%m-%dT%H:%M:%SZ".
+    pub fn expires(&mut self, expires: &str) -> &mut CreateSharedLinkRequestBuilder<T> {
+        self.settings.expires = Some(expires.to_owned());
+        self
+    }
+
+    pub fn audience(&mut self, audience: LinkAudience) -> &mut CreateSharedLinkRequestBuilder<T> {
+        self.settings.audience = Some(audience);
+        self
+    }
+
+    pub fn access(&mut self,
+                  access: RequestedLinkAccessLevel)
+                  -> &mut CreateSharedLinkRequestBuilder<T> {
+        self.settings.access = Some(access);
+        self
+    }
+
+    pub fn allow_download(&mut self,
+                          allow_download: bool)
+                          -> &mut CreateSharedLinkRequestBuilder<T> {
+        self.settings.allow_download = Some(allow_download);
+        self
+    }
+
+    pub fn send(&self) -> Result<Response<SharedLinkMetadata>> {
+        let url = Url::parse(super::BASE_URL)?.join("create_shared_link_with_settings")?;
+        let settings = if self.settings == SharedLinkSettings::default() {
+            None
+        } else {
+            Some(self.settings.clone())
+        };
+        let resp_w_err = self.client
+            .rpc_request(url,
+                         &CreateSharedLinkWithSettingsArg {
+                             path: self.path.clone(),
+                             settings: settings,
+                         })?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::CreateSharedLinkWithSettingsErr(e).into()),
+        }
+    }
+
+    /// Creates the link, or returns the existing link of the path if there's already one.
+    ///
+    /// An existing link keeps its own settings, which may differ from the requested ones.
+    pub fn get_or_create(&self) -> Result<SharedLinkMetadata> {
+        let already_exists = match self.send() {
+            Ok(resp) => return Ok(resp.body),
+            Err(Error(CreateSharedLinkWithSettingsErr(e), state)) => {
+                match e.error {
+                    CreateSharedLinkWithSettingsError::SharedLinkAlreadyExists {
+                        shared_link_already_exists
+                    } => shared_link_already_exists,
+                    _ => return Err(Error(CreateSharedLinkWithSettingsErr(e), state)),
+                }
+            }
+            Err(e) => return Err(e),
+        };
+
+        if let Some(SharedLinkAlreadyExistsMetadata::Metadata { metadata }) = already_exists {
+            return Ok(metadata);
+        }
+        let links = list_shared_links(&self.client,
+                                      &ListSharedLinksArg {
+                                          path: Some(self.path.clone()),
+                                          cursor: None,
+                                          direct_only: Some(true),
+                                      })?;
+        links.body
+            .links
+            .into_iter()
+            .next()
+            .ok_or_else(|| SharedLinkNotFound(self.path.clone()).into())
+    }
+}
+
+/**
+ * list_shared_links
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ListSharedLinksArg {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub direct_only: Option<bool>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ListSharedLinksResult {
+    pub links: Vec<SharedLinkMetadata>,
+    pub has_more: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+pub struct ListSharedLinksRequestBuilder<T> {
+    client: T,
+    arg: ListSharedLinksArg,
+}
+
+impl<T> ListSharedLinksRequestBuilder<T>
+    where T: RPCClient + Clone
+{
+    pub fn new(client: &T) -> ListSharedLinksRequestBuilder<T> {
+        ListSharedLinksRequestBuilder {
+            client: client.clone(),
+            arg: ListSharedLinksArg {
+                path: None,
+                cursor: None,
+                direct_only: None,
+            },
+        }
+    }
+
+    /// Only lists the links to the file or folder at `path` and, unless `direct_only` is set,
+    /// to its parent folders.
+    pub fn path(&mut self, path: &str) -> &mut ListSharedLinksRequestBuilder<T> {
+        self.arg.path = Some(path.to_owned());
+        self
+    }
+
+    pub fn direct_only(&mut self, direct_only: bool) -> &mut ListSharedLinksRequestBuilder<T> {
+        self.arg.direct_only = Some(direct_only);
+        self
+    }
+
+    /// Fetches the page following the one which returned `cursor`.
+    pub fn cursor(&mut self, cursor: &str) -> &mut ListSharedLinksRequestBuilder<T> {
+        self.arg.cursor = Some(cursor.to_owned());
+        self
+    }
+
+    pub fn send(&self) -> Result<Response<ListSharedLinksResult>> {
+        list_shared_links(&self.client, &self.arg)
+    }
+
+    /// Returns an iterator over every link, fetching the following pages as needed.
+    pub fn iter(&self) -> PaginatedIter<ListSharedLinksPages<T>> {
+        ListSharedLinksPages {
+                client: self.client.clone(),
+                arg: self.arg.clone(),
+            }
+            .iter_all()
+    }
+}
+
+/// The pages of links returned by list_shared_links.
+pub struct ListSharedLinksPages<T> {
+    pub client: T,
+    pub arg: ListSharedLinksArg,
+}
+
+impl<T: RPCClient> Paginated for ListSharedLinksPages<T> {
+    type Item = SharedLinkMetadata;
+    type Error = Error;
+
+    fn first_page(&self) -> Result<Page<SharedLinkMetadata>> {
+        Ok(list_page(list_shared_links(&self.client, &self.arg)?.body))
+    }
+
+    fn next_page(&self, cursor: &str) -> Result<Page<SharedLinkMetadata>> {
+        let arg = ListSharedLinksArg { cursor: Some(cursor.to_owned()), ..self.arg.clone() };
+        Ok(list_page(list_shared_links(&self.client, &arg)?.body))
+    }
+
+    fn should_restart(&self, error: &Error) -> bool {
+        match *error.kind() {
+            ListSharedLinksErr(ref e) => e.error == ListSharedLinksError::Reset,
+            _ => false,
+        }
+    }
+
+    fn item_key(&self, link: &SharedLinkMetadata) -> String {
+        link.url().to_owned()
+    }
+}
+
+fn list_page(result: ListSharedLinksResult) -> Page<SharedLinkMetadata> {
+    Page {
+        items: result.links,
+        cursor: result.cursor.unwrap_or_default(),
+        cursor_expiration: None,
+        has_more: result.has_more,
+    }
+}
+
+pub fn list_shared_links<T: RPCClient>(client: &T,
+                                       arg: &ListSharedLinksArg)
+                                       -> Result<Response<ListSharedLinksResult>> {
+    let url = Url::parse(super::BASE_URL)?.join("list_shared_links")?;
+    let resp_w_err = client.rpc_request(url, arg)?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::ListSharedLinksErr(e).into()),
+    }
+}
+
+/**
+ * modify_shared_link_settings
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ModifySharedLinkSettingsArgs {
+    pub url: String,
+    pub settings: SharedLinkSettings,
+    pub remove_expiration: bool,
+}
+
+/**
+ * revoke_shared_link
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct RevokeSharedLinkArg {
+    pub url: String,
+}
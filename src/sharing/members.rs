@@ -0,0 +1,774 @@
+use reqwest::Url;
+
+use sharing::errors::*;
+use sharing::folders::AccessLevel;
+use http::{Response, ResponseWithErr};
+use http::RPCClient;
+use http::async_job::{AsyncJob, AsyncJobStatus, LaunchResult, PollError, PollOptions};
+use http::pagination::{Page, Paginated, PaginatedIter};
+
+pub use paper::users::MemberSelector;
+
+/// The most folder members Dropbox returns per page.
+pub const MAX_LIST_FOLDER_MEMBERS_LIMIT: u32 = 1000;
+/// The most file members Dropbox returns per page.
+pub const MAX_LIST_FILE_MEMBERS_LIMIT: u32 = 300;
+
+/**
+ * memberships
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct UserInfo {
+    pub account_id: String,
+    pub email: String,
+    pub display_name: String,
+    pub same_team: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_member_id: Option<String>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct GroupInfo {
+    pub group_name: String,
+    pub group_id: String,
+    pub is_member: bool,
+    pub is_owner: bool,
+    pub same_team: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_count: Option<u32>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum InviteeInfo {
+    Email { email: String },
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct UserMembershipInfo {
+    pub access_type: AccessLevel,
+    pub user: UserInfo,
+    /// Inherited members got their access from a parent folder.
+    pub is_inherited: bool,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct GroupMembershipInfo {
+    pub access_type: AccessLevel,
+    pub group: GroupInfo,
+    pub is_inherited: bool,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct InviteeMembershipInfo {
+    pub access_type: AccessLevel,
+    pub invitee: InviteeInfo,
+    /// Set once the invitee has a Dropbox account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<UserInfo>,
+    pub is_inherited: bool,
+}
+
+/// A member of a shared folder or file, as yielded by the member list iterators.
+#[derive(PartialEq,Eq,Debug,Clone)]
+pub enum MembershipInfo {
+    User(UserMembershipInfo),
+    Group(GroupMembershipInfo),
+    Invitee(InviteeMembershipInfo),
+}
+
+/// The members of a shared folder or file, `SharedFolderMembers` and `SharedFileMembers` in the
+/// Dropbox documentation.
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct SharedMembers {
+    pub users: Vec<UserMembershipInfo>,
+    pub groups: Vec<GroupMembershipInfo>,
+    pub invitees: Vec<InviteeMembershipInfo>,
+    /// Only set when there are more members to list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+fn members_page(members: SharedMembers) -> Page<MembershipInfo> {
+    let items = members.users
+        .into_iter()
+        .map(MembershipInfo::User)
+        .chain(members.groups.into_iter().map(MembershipInfo::Group))
+        .chain(members.invitees.into_iter().map(MembershipInfo::Invitee))
+        .collect();
+    Page {
+        items: items,
+        has_more: members.cursor.is_some(),
+        cursor: members.cursor.unwrap_or_default(),
+        cursor_expiration: None,
+    }
+}
+
+fn membership_key(member: &MembershipInfo) -> String {
+    match *member {
+        MembershipInfo::User(ref user) => format!("user:{}", user.user.account_id),
+        MembershipInfo::Group(ref group) => format!("group:{}", group.group.group_id),
+        MembershipInfo::Invitee(InviteeMembershipInfo { invitee: InviteeInfo::Email { ref email },
+                                                        .. }) => format!("invitee:{}", email),
+    }
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct MemberAccessLevelResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_level: Option<AccessLevel>,
+    /// A message explaining why the access level of the member may differ from the requested
+    /// one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+}
+
+/**
+ * add_folder_member
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct AddMember {
+    pub member: MemberSelector,
+    pub access_level: AccessLevel,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct AddFolderMemberArg {
+    pub shared_folder_id: String,
+    pub members: Vec<AddMember>,
+    pub quiet: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_message: Option<String>,
+}
+
+pub struct AddFolderMemberRequestBuilder<T> {
+    client: T,
+    arg: AddFolderMemberArg,
+}
+
+impl<T> AddFolderMemberRequestBuilder<T>
+    where T: RPCClient + Clone
+{
+    pub fn new(client: &T, shared_folder_id: &str) -> AddFolderMemberRequestBuilder<T> {
+        AddFolderMemberRequestBuilder {
+            client: client.clone(),
+            arg: AddFolderMemberArg {
+                shared_folder_id: shared_folder_id.to_owned(),
+                members: Vec::new(),
+                quiet: false,
+                custom_message: None,
+            },
+        }
+    }
+
+    pub fn add_member(&mut self,
+                      member: &MemberSelector,
+                      access_level: AccessLevel)
+                      -> &mut AddFolderMemberRequestBuilder<T> {
+        self.arg.members.push(AddMember {
+            member: member.clone(),
+            access_level: access_level,
+        });
+        self
+    }
+
+    /// Doesn't notify the new members by email.
+    pub fn quiet(&mut self, quiet: bool) -> &mut AddFolderMemberRequestBuilder<T> {
+        self.arg.quiet = quiet;
+        self
+    }
+
+    pub fn custom_message(&mut self,
+                          custom_message: &str)
+                          -> &mut AddFolderMemberRequestBuilder<T> {
+        self.arg.custom_message = Some(custom_message.to_owned());
+        self
+    }
+
+    pub fn send(&self) -> Result<Response<()>> {
+        add_folder_member(&self.client, &self.arg)
+    }
+}
+
+pub fn add_folder_member<T: RPCClient>(client: &T,
+                                       arg: &AddFolderMemberArg)
+                                       -> Result<Response<()>> {
+    let url = Url::parse(super::BASE_URL)?.join("add_folder_member")?;
+    let resp_w_err = client.rpc_request(url, arg)?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::AddFolderMemberErr(e).into()),
+    }
+}
+
+/**
+ * remove_folder_member
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct RemoveFolderMemberArg {
+    pub shared_folder_id: String,
+    pub member: MemberSelector,
+    /// Keeps a copy of the folder's contents in the Dropbox of the removed member.
+    pub leave_a_copy: bool,
+}
+
+/// Removes `member` from the shared folder and blocks until Dropbox finished the job removing
+/// it, returning the access the member keeps through a parent folder if any.
+pub fn remove_folder_member<T: RPCClient + Clone>(client: &T,
+                                                  arg: &RemoveFolderMemberArg,
+                                                  poll_options: PollOptions)
+                                                  -> Result<MemberAccessLevelResult> {
+    let url = Url::parse(super::BASE_URL)?.join("remove_folder_member")?;
+    let resp_w_err: ResponseWithErr<LaunchResult<MemberAccessLevelResult>, _> =
+        client.rpc_request(url, arg)?;
+    let async_job_id = match resp_w_err {
+        ResponseWithErr::Ok(Response { body: LaunchResult::AsyncJobId { async_job_id }, .. }) => {
+            async_job_id
+        }
+        ResponseWithErr::Ok(Response { body: LaunchResult::Complete(result), .. }) => {
+            return Ok(result)
+        }
+        ResponseWithErr::Err(e) => return Err(ErrorKind::RemoveFolderMemberErr(e).into()),
+    };
+
+    let check_url = Url::parse(super::BASE_URL)?.join("check_remove_member_job_status")?;
+    let status: ResponseWithErr<AsyncJobStatus<MemberAccessLevelResult,
+                                               RemoveFolderMemberError>,
+                                PollError> = AsyncJob::new(client, check_url, &async_job_id)
+        .options(poll_options)
+        .wait()?;
+    match status {
+        ResponseWithErr::Ok(Response { body: AsyncJobStatus::Complete(result), .. }) => Ok(result),
+        ResponseWithErr::Ok(Response { body: AsyncJobStatus::Failed { failed }, .. }) => {
+            Err(ErrorKind::RemoveFolderMemberFailed(failed).into())
+        }
+        ResponseWithErr::Ok(_) => unreachable!("sharing: remove_folder_member job in progress"),
+        ResponseWithErr::Err(e) => Err(ErrorKind::PollErr(e).into()),
+    }
+}
+
+/**
+ * update_folder_member
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct UpdateFolderMemberArg {
+    pub shared_folder_id: String,
+    pub member: MemberSelector,
+    pub access_level: AccessLevel,
+}
+
+pub fn update_folder_member<T: RPCClient>(client: &T,
+                                          arg: &UpdateFolderMemberArg)
+                                          -> Result<Response<MemberAccessLevelResult>> {
+    let url = Url::parse(super::BASE_URL)?.join("update_folder_member")?;
+    let resp_w_err = client.rpc_request(url, arg)?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::UpdateFolderMemberErr(e).into()),
+    }
+}
+
+/**
+ * list_folder_members
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ListFolderMembersArgs {
+    pub shared_folder_id: String,
+    pub limit: u32,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ListMembersContinueArg {
+    pub cursor: String,
+}
+
+pub struct ListFolderMembersRequestBuilder<T> {
+    client: T,
+    arg: ListFolderMembersArgs,
+}
+
+impl<T> ListFolderMembersRequestBuilder<T>
+    where T: RPCClient + Clone
+{
+    /// Defaults to pages of 1000 members, the most Dropbox returns at once.
+    pub fn new(client: &T, shared_folder_id: &str) -> ListFolderMembersRequestBuilder<T> {
+        ListFolderMembersRequestBuilder {
+            client: client.clone(),
+            arg: ListFolderMembersArgs {
+                shared_folder_id: shared_folder_id.to_owned(),
+                limit: MAX_LIST_FOLDER_MEMBERS_LIMIT,
+            },
+        }
+    }
+
+    /// The maximum number of members per page, up to 1000.
+    pub fn limit(&mut self, limit: u32) -> &mut ListFolderMembersRequestBuilder<T> {
+        self.arg.limit = limit;
+        self
+    }
+
+    pub fn send(&self) -> Result<Response<SharedMembers>> {
+        list_folder_members(&self.client, &self.arg)
+    }
+
+    /// Returns an iterator over every user, group and invitee, fetching the following pages as
+    /// needed.
+    pub fn iter(&self) -> PaginatedIter<ListFolderMembersPages<T>> {
+        ListFolderMembersPages {
+                client: self.client.clone(),
+                arg: self.arg.clone(),
+            }
+            .iter_all()
+    }
+}
+
+/// The pages of members returned by list_folder_members.
+pub struct ListFolderMembersPages<T> {
+    pub client: T,
+    pub arg: ListFolderMembersArgs,
+}
+
+impl<T: RPCClient> Paginated for ListFolderMembersPages<T> {
+    type Item = MembershipInfo;
+    type Error = Error;
+
+    fn first_page(&self) -> Result<Page<MembershipInfo>> {
+        Ok(members_page(list_folder_members(&self.client, &self.arg)?.body))
+    }
+
+    fn next_page(&self, cursor: &str) -> Result<Page<MembershipInfo>> {
+        Ok(members_page(list_folder_members_continue(&self.client, cursor)?.body))
+    }
+
+    fn item_key(&self, member: &MembershipInfo) -> String {
+        membership_key(member)
+    }
+}
+
+pub fn list_folder_members<T: RPCClient>(client: &T,
+                                         arg: &ListFolderMembersArgs)
+                                         -> Result<Response<SharedMembers>> {
+    let url = Url::parse(super::BASE_URL)?.join("list_folder_members")?;
+    let resp_w_err = client.rpc_request(url, arg)?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::ListFolderMembersErr(e).into()),
+    }
+}
+
+pub fn list_folder_members_continue<T: RPCClient>(client: &T,
+                                                  cursor: &str)
+                                                  -> Result<Response<SharedMembers>> {
+    let url = Url::parse(super::BASE_URL)?.join("list_folder_members/continue")?;
+    let resp_w_err = client.rpc_request(url,
+                     &ListMembersContinueArg { cursor: cursor.to_owned() })?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::ListFolderMembersContinueErr(e).into()),
+    }
+}
+
+/**
+ * desired folder membership
+ **/
+/// The members added, updated and removed to reach a desired folder membership.
+#[derive(PartialEq,Eq,Debug,Clone,Default)]
+pub struct MembershipChanges {
+    pub added: Vec<(MemberSelector, AccessLevel)>,
+    pub updated: Vec<(MemberSelector, AccessLevel)>,
+    pub removed: Vec<MemberSelector>,
+}
+
+impl MembershipChanges {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.updated.is_empty() && self.removed.is_empty()
+    }
+}
+
+fn selects_user(selector: &MemberSelector, user: &UserInfo) -> bool {
+    match *selector {
+        MemberSelector::DropboxId { ref dropbox_id } => {
+            *dropbox_id == user.account_id ||
+            user.team_member_id.as_ref().map_or(false, |x| x == dropbox_id)
+        }
+        MemberSelector::Email { ref email } => email.to_lowercase() == user.email.to_lowercase(),
+    }
+}
+
+fn selects_invitee(selector: &MemberSelector, invitee: &InviteeMembershipInfo) -> bool {
+    let InviteeInfo::Email { ref email } = invitee.invitee;
+    match *selector {
+        MemberSelector::Email { email: ref selected } => {
+            selected.to_lowercase() == email.to_lowercase()
+        }
+        MemberSelector::DropboxId { .. } => {
+            invitee.user.as_ref().map_or(false, |user| selects_user(selector, user))
+        }
+    }
+}
+
+/// Computes the changes making the explicit user and invitee members of a folder match
+/// `desired`. Users are matched by email or Dropbox id and emails are compared case
+/// insensitively.
+///
+/// The owner, the members inherited from a parent folder and the groups are left untouched since
+/// they can't be changed through the folder's membership.
+pub fn membership_changes(current: &SharedMembers,
+                          desired: &[(MemberSelector, AccessLevel)])
+                          -> MembershipChanges {
+    let mut changes = MembershipChanges::default();
+    for &(ref selector, access_level) in desired {
+        let current_access = current.users
+            .iter()
+            .filter(|x| selects_user(selector, &x.user))
+            .map(|x| (x.access_type, x.is_inherited))
+            .chain(current.invitees
+                .iter()
+                .filter(|x| selects_invitee(selector, x))
+                .map(|x| (x.access_type, x.is_inherited)))
+            .next();
+        match current_access {
+            None => changes.added.push((selector.clone(), access_level)),
+            Some((AccessLevel::Owner, _)) |
+            Some((_, true)) => {}
+            Some((access_type, false)) => {
+                if access_type != access_level {
+                    changes.updated.push((selector.clone(), access_level));
+                }
+            }
+        }
+    }
+
+    let removable = |access_type: AccessLevel, is_inherited: bool| {
+        access_type != AccessLevel::Owner && !is_inherited
+    };
+    for user in &current.users {
+        if removable(user.access_type, user.is_inherited) &&
+           !desired.iter().any(|&(ref selector, _)| selects_user(selector, &user.user)) {
+            let dropbox_id = user.user.account_id.clone();
+            changes.removed.push(MemberSelector::DropboxId { dropbox_id: dropbox_id });
+        }
+    }
+    for invitee in &current.invitees {
+        if removable(invitee.access_type, invitee.is_inherited) &&
+           !desired.iter().any(|&(ref selector, _)| selects_invitee(selector, invitee)) {
+            let InviteeInfo::Email { ref email } = invitee.invitee;
+            changes.removed.push(MemberSelector::Email { email: email.clone() });
+        }
+    }
+    changes
+}
+
+/**
+ * add_file_member
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct AddFileMemberArgs {
+    /// The path or id of the file.
+    pub file: String,
+    pub members: Vec<MemberSelector>,
+    pub access_level: AccessLevel,
+    pub quiet: bool,
+    pub add_message_as_comment: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_message: Option<String>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum FileMemberActionIndividualResult {
+    /// The access level granted to the member, which may be more than the requested one if it
+    /// already had access to the file.
+    Success { success: Option<AccessLevel> },
+    MemberError { member_error: FileMemberActionError },
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct FileMemberActionResult {
+    pub member: MemberSelector,
+    pub result: FileMemberActionIndividualResult,
+}
+
+pub struct AddFileMemberRequestBuilder<T> {
+    client: T,
+    arg: AddFileMemberArgs,
+}
+
+impl<T> AddFileMemberRequestBuilder<T>
+    where T: RPCClient + Clone
+{
+    /// Defaults to viewer access, as the Dropbox API does.
+    pub fn new(client: &T, file: &str) -> AddFileMemberRequestBuilder<T> {
+        AddFileMemberRequestBuilder {
+            client: client.clone(),
+            arg: AddFileMemberArgs {
+                file: file.to_owned(),
+                members: Vec::new(),
+                access_level: AccessLevel::Viewer,
+                quiet: false,
+                add_message_as_comment: false,
+                custom_message: None,
+            },
+        }
+    }
+
+    pub fn add_member(&mut self, member: &MemberSelector) -> &mut AddFileMemberRequestBuilder<T> {
+        self.arg.members.push(member.clone());
+        self
+    }
+
+    /// The access level granted to all the members added by this request.
+    pub fn access_level(&mut self,
+                        access_level: AccessLevel)
+                        -> &mut AddFileMemberRequestBuilder<T> {
+        self.arg.access_level = access_level;
+        self
+    }
+
+    pub fn quiet(&mut self, quiet: bool) -> &mut AddFileMemberRequestBuilder<T> {
+        self.arg.quiet = quiet;
+        self
+    }
+
+    /// Posts the custom message as a comment on the file as well.
+    pub fn custom_message(&mut self,
+                          custom_message: &str,
+                          add_message_as_comment: bool)
+                          -> &mut AddFileMemberRequestBuilder<T> {
+        self.arg.custom_message = Some(custom_message.to_owned());
+        self.arg.add_message_as_comment = add_message_as_comment;
+        self
+    }
+
+    pub fn send(&self) -> Result<Response<Vec<FileMemberActionResult>>> {
+        let url = Url::parse(super::BASE_URL)?.join("add_file_member")?;
+        let resp_w_err = self.client.rpc_request(url, &self.arg)?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::AddFileMemberErr(e).into()),
+        }
+    }
+}
+
+/**
+ * remove_file_member_2
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct RemoveFileMemberArg {
+    pub file: String,
+    pub member: MemberSelector,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum FileMemberRemoveActionResult {
+    /// The access the member keeps through a shared parent folder, if any.
+    Success(MemberAccessLevelResult),
+    MemberError { member_error: FileMemberActionError },
+}
+
+/**
+ * list_file_members
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ListFileMembersArg {
+    pub file: String,
+    pub include_inherited: bool,
+    pub limit: u32,
+}
+
+pub struct ListFileMembersRequestBuilder<T> {
+    client: T,
+    arg: ListFileMembersArg,
+}
+
+impl<T> ListFileMembersRequestBuilder<T>
+    where T: RPCClient + Clone
+{
+    /// Defaults to pages of 300 members, the most Dropbox returns at once, including the members
+    /// inherited from the parent folders.
+    pub fn new(client: &T, file: &str) -> ListFileMembersRequestBuilder<T> {
+        ListFileMembersRequestBuilder {
+            client: client.clone(),
+            arg: ListFileMembersArg {
+                file: file.to_owned(),
+                include_inherited: true,
+                limit: MAX_LIST_FILE_MEMBERS_LIMIT,
+            },
+        }
+    }
+
+    pub fn include_inherited(&mut self,
+                             include_inherited: bool)
+                             -> &mut ListFileMembersRequestBuilder<T> {
+        self.arg.include_inherited = include_inherited;
+        self
+    }
+
+    /// The maximum number of members per page, up to 300.
+    pub fn limit(&mut self, limit: u32) -> &mut ListFileMembersRequestBuilder<T> {
+        self.arg.limit = limit;
+        self
+    }
+
+    pub fn send(&self) -> Result<Response<SharedMembers>> {
+        list_file_members(&self.client, &self.arg)
+    }
+
+    /// Returns an iterator over every user, group and invitee, fetching the following pages as
+    /// needed.
+    pub fn iter(&self) -> PaginatedIter<ListFileMembersPages<T>> {
+        ListFileMembersPages {
+                client: self.client.clone(),
+                arg: self.arg.clone(),
+            }
+            .iter_all()
+    }
+}
+
+/// The pages of members returned by list_file_members.
+pub struct ListFileMembersPages<T> {
+    pub client: T,
+    pub arg: ListFileMembersArg,
+}
+
+impl<T: RPCClient> Paginated for ListFileMembersPages<T> {
+    type Item = MembershipInfo;
+    type Error = Error;
+
+    fn first_page(&self) -> Result<Page<MembershipInfo>> {
+        Ok(members_page(list_file_members(&self.client, &self.arg)?.body))
+    }
+
+    fn next_page(&self, cursor: &str) -> Result<Page<MembershipInfo>> {
+        Ok(members_page(list_file_members_continue(&self.client, cursor)?.body))
+    }
+
+    fn item_key(&self, member: &MembershipInfo) -> String {
+        membership_key(member)
+    }
+}
+
+pub fn list_file_members<T: RPCClient>(client: &T,
+                                       arg: &ListFileMembersArg)
+                                       -> Result<Response<SharedMembers>> {
+    let url = Url::parse(super::BASE_URL)?.join("list_file_members")?;
+    let resp_w_err = client.rpc_request(url, arg)?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::ListFileMembersErr(e).into()),
+    }
+}
+
+pub fn list_file_members_continue<T: RPCClient>(client: &T,
+                                                cursor: &str)
+                                                -> Result<Response<SharedMembers>> {
+    let url = Url::parse(super::BASE_URL)?.join("list_file_members/continue")?;
+    let resp_w_err = client.rpc_request(url,
+                     &ListMembersContinueArg { cursor: cursor.to_owned() })?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::ListFileMembersContinueErr(e).into()),
+    }
+}
+
+/**
+ * list_file_members/batch
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ListFileMembersBatchArg {
+    pub files: Vec<String>,
+    /// The maximum number of members returned per file, up to 20.
+    pub limit: u32,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ListFileMembersCountResult {
+    /// The first members of the file, use list_file_members to list them all.
+    pub members: SharedMembers,
+    pub member_count: u32,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum ListFileMembersIndividualResult {
+    Result(ListFileMembersCountResult),
+    AccessError { access_error: SharingFileAccessError },
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct ListFileMembersBatchResult {
+    pub file: String,
+    pub result: ListFileMembersIndividualResult,
+}
+
+pub fn list_file_members_batch<T: RPCClient>(client: &T,
+                                             arg: &ListFileMembersBatchArg)
+                                             -> Result<Response<Vec<ListFileMembersBatchResult>>> {
+    let url = Url::parse(super::BASE_URL)?.join("list_file_members/batch")?;
+    let resp_w_err = client.rpc_request(url, arg)?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::ListFileMembersBatchErr(e).into()),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(account_id: &str, email: &str, access_type: AccessLevel) -> UserMembershipInfo {
+        UserMembershipInfo {
+            access_type: access_type,
+            user: UserInfo {
+                account_id: account_id.to_owned(),
+                email: email.to_owned(),
+                display_name: email.to_owned(),
+                same_team: false,
+                team_member_id: None,
+            },
+            is_inherited: false,
+        }
+    }
+
+    fn email(email: &str) -> MemberSelector {
+        MemberSelector::Email { email: email.to_owned() }
+    }
+
+    #[test]
+    fn test_membership_changes() {
+        let mut inherited = user("dbid:inherited", "inherited@example.com", AccessLevel::Editor);
+        inherited.is_inherited = true;
+        let current = SharedMembers {
+            users: vec![user("dbid:owner", "owner@example.com", AccessLevel::Owner),
+                        user("dbid:kept", "kept@example.com", AccessLevel::Editor),
+                        user("dbid:changed", "changed@example.com", AccessLevel::Viewer),
+                        user("dbid:removed", "removed@example.com", AccessLevel::Viewer),
+                        inherited],
+            groups: Vec::new(),
+            invitees: vec![InviteeMembershipInfo {
+                               access_type: AccessLevel::Viewer,
+                               invitee: InviteeInfo::Email {
+                                   email: "invited@example.com".to_owned(),
+                               },
+                               user: None,
+                               is_inherited: false,
+                           }],
+            cursor: None,
+        };
+        let desired = vec![(email("Kept@Example.com"), AccessLevel::Editor),
+                           (MemberSelector::DropboxId { dropbox_id: "dbid:changed".to_owned() },
+                            AccessLevel::Editor),
+                           (email("new@example.com"), AccessLevel::Viewer)];
+
+        let changes = membership_changes(&current, &desired);
+        assert_eq!(changes.added, vec![(email("new@example.com"), AccessLevel::Viewer)]);
+        assert_eq!(changes.updated, vec![desired[1].clone()]);
+        assert_eq!(changes.removed,
+                   vec![MemberSelector::DropboxId { dropbox_id: "dbid:removed".to_owned() },
+                        email("invited@example.com")]);
+        // Only the owner and the inherited member are kept.
+        assert_eq!(membership_changes(&current, &[]).removed.len(), 4);
+    }
+}
@@ -0,0 +1,481 @@
+//! [Dropbox Sharing Documentation](https://www.dropbox.com/developers/documentation/http/documentation#sharing)
+//!
+//! This namespace contains endpoints and data types for creating and managing shared links and
+//! shared folders.
+//!
+
+pub mod errors;
+pub mod folders;
+pub mod links;
+pub mod link_content;
+pub mod members;
+
+use reqwest::Url;
+
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use self::errors::*;
+use http::{Response, ResponseWithErr, ContentResponse};
+use http::RPCClient;
+
+use self::links::{CreateSharedLinkRequestBuilder, ListSharedLinksRequestBuilder,
+                  SharedLinkMetadata, SharedLinkSettings, ModifySharedLinkSettingsArgs,
+                  RevokeSharedLinkArg};
+use self::link_content::GetSharedLinkMetadataArg;
+use self::folders::{ShareFolderRequestBuilder, UpdateFolderPolicyRequestBuilder,
+                    ListFoldersRequestBuilder, ListFoldersResult, FolderListing,
+                    SharedFolderMetadata, SharedFolderArg, UnshareFolderArg,
+                    RelinquishFolderMembershipArg, TransferFolderArg};
+use self::folders::AccessLevel;
+use self::members::{AddFolderMemberRequestBuilder, AddFolderMemberArg, AddMember,
+                    AddFileMemberRequestBuilder, FileMemberRemoveActionResult,
+                    ListFolderMembersRequestBuilder, ListFileMembersRequestBuilder,
+                    ListFileMembersBatchArg, ListFileMembersBatchResult, MemberAccessLevelResult,
+                    MemberSelector, MembershipChanges, MembershipInfo, RemoveFileMemberArg,
+                    RemoveFolderMemberArg, SharedMembers, UpdateFolderMemberArg};
+use http::async_job::{LaunchEmptyResult, PollOptions};
+
+static BASE_URL: &'static str = "https://api.dropboxapi.com/2/sharing/";
+static CONTENT_BASE_URL: &'static str = "https://content.dropboxapi.com/2/sharing/";
+
+/// A client to interface with the endpoints in the Sharing namespace of the Dropbox APIs
+#[derive(Debug,Clone)]
+pub struct Sharing {
+    access_token: Rc<String>,
+}
+
+impl ::http::HasAccessToken for Sharing {
+    fn access_token(&self) -> &str {
+        self.access_token.as_str()
+    }
+}
+
+impl Sharing {
+    pub fn new(access_token: Rc<String>) -> Sharing {
+        Sharing { access_token: Rc::clone(&access_token) }
+    }
+
+    /// Create a shared link with custom settings. If no settings are given then the default
+    /// visibility is public.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-create_shared_link_with_settings)
+    pub fn create_shared_link_with_settings(&self,
+                                            path: &str)
+                                            -> CreateSharedLinkRequestBuilder<Sharing> {
+        CreateSharedLinkRequestBuilder::new(self, path)
+    }
+
+    /// Returns the shared link of the file or folder at `path`, creating it with the default
+    /// settings if there's none.
+    pub fn get_or_create_shared_link(&self, path: &str) -> Result<SharedLinkMetadata> {
+        self.create_shared_link_with_settings(path).get_or_create()
+    }
+
+    /// List shared links of this user. If no path is given, returns a list of all shared links
+    /// for the current user.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-list_shared_links)
+    pub fn list_shared_links(&self) -> ListSharedLinksRequestBuilder<Sharing> {
+        ListSharedLinksRequestBuilder::new(self)
+    }
+
+    /// Modify the shared link's settings. If `remove_expiration` is true, the link's expiration
+    /// is removed and `settings.expires` is ignored.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-modify_shared_link_settings)
+    pub fn modify_shared_link_settings(&self,
+                                       url: &str,
+                                       settings: &SharedLinkSettings,
+                                       remove_expiration: bool)
+                                       -> Result<Response<SharedLinkMetadata>> {
+        let endpoint = Url::parse(BASE_URL)?.join("modify_shared_link_settings")?;
+        let resp_w_err = self.rpc_request(endpoint,
+                         &ModifySharedLinkSettingsArgs {
+                             url: url.to_owned(),
+                             settings: settings.clone(),
+                             remove_expiration: remove_expiration,
+                         })?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::ModifySharedLinkSettingsErr(e).into()),
+        }
+    }
+
+    /// Revoke a shared link. Note that even after revoking a shared link to a file, the file may
+    /// be accessible if there are shared links leading to any of the file parent folders.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-revoke_shared_link)
+    pub fn revoke_shared_link(&self, url: &str) -> Result<Response<()>> {
+        let endpoint = Url::parse(BASE_URL)?.join("revoke_shared_link")?;
+        let resp_w_err = self.rpc_request(endpoint, &RevokeSharedLinkArg { url: url.to_owned() })?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::RevokeSharedLinkErr(e).into()),
+        }
+    }
+
+    /// Get the shared link's metadata. `path` selects a file or folder inside a shared folder
+    /// link and `link_password` is needed for password protected links.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-get_shared_link_metadata)
+    pub fn get_shared_link_metadata(&self,
+                                    url: &str,
+                                    path: Option<&str>,
+                                    link_password: Option<&str>)
+                                    -> Result<Response<SharedLinkMetadata>> {
+        link_content::get_shared_link_metadata(self,
+                                               &GetSharedLinkMetadataArg {
+                                                   url: url.to_owned(),
+                                                   path: path.map(|x| x.to_owned()),
+                                                   link_password: link_password
+                                                       .map(|x| x.to_owned()),
+                                               })
+    }
+
+    /// Download the shared link's file from a user's Dropbox. The content is streamed as it is
+    /// read from the returned response. `path` selects a file inside a shared folder link.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-get_shared_link_file)
+    pub fn get_shared_link_file(&self,
+                                url: &str,
+                                path: Option<&str>,
+                                link_password: Option<&str>)
+                                -> Result<ContentResponse<SharedLinkMetadata>> {
+        link_content::get_shared_link_file(self,
+                                           &GetSharedLinkMetadataArg {
+                                               url: url.to_owned(),
+                                               path: path.map(|x| x.to_owned()),
+                                               link_password: link_password.map(|x| x.to_owned()),
+                                           })
+    }
+
+    /// Downloads the file or folder behind a shared link into `destination`, without adding it
+    /// to the user's Dropbox. Returns the paths of the downloaded files.
+    pub fn mirror_shared_link<P: AsRef<Path>>(&self,
+                                              url: &str,
+                                              link_password: Option<&str>,
+                                              destination: P)
+                                              -> Result<Vec<PathBuf>> {
+        link_content::mirror_shared_link(self, url, link_password, destination)
+    }
+
+    /// Share a folder with collaborators. Blocks until the folder is shared, polling the job
+    /// launched by Dropbox if sharing it takes a while.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-share_folder)
+    pub fn share_folder(&self, path: &str) -> ShareFolderRequestBuilder<Sharing> {
+        ShareFolderRequestBuilder::new(self, path)
+    }
+
+    /// The current user mounts the designated folder. Mount a shared folder for a user after
+    /// they have been added as a member. Once mounted, the shared folder will appear in their
+    /// Dropbox.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-mount_folder)
+    pub fn mount_folder(&self, shared_folder_id: &str) -> Result<Response<SharedFolderMetadata>> {
+        let url = Url::parse(BASE_URL)?.join("mount_folder")?;
+        let resp_w_err = self.rpc_request(url,
+                         &SharedFolderArg { shared_folder_id: shared_folder_id.to_owned() })?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::MountFolderErr(e).into()),
+        }
+    }
+
+    /// The current user unmounts the designated folder. They can re-mount the folder at a later
+    /// time using mount_folder.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-unmount_folder)
+    pub fn unmount_folder(&self, shared_folder_id: &str) -> Result<Response<()>> {
+        let url = Url::parse(BASE_URL)?.join("unmount_folder")?;
+        let resp_w_err = self.rpc_request(url,
+                         &SharedFolderArg { shared_folder_id: shared_folder_id.to_owned() })?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::UnmountFolderErr(e).into()),
+        }
+    }
+
+    /// Allows a shared folder owner to unshare the folder. Blocks until the folder is unshared.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-unshare_folder)
+    pub fn unshare_folder(&self, shared_folder_id: &str, leave_a_copy: bool) -> Result<()> {
+        let url = Url::parse(BASE_URL)?.join("unshare_folder")?;
+        let resp_w_err: ResponseWithErr<LaunchEmptyResult, _> = self.rpc_request(url,
+                         &UnshareFolderArg {
+                             shared_folder_id: shared_folder_id.to_owned(),
+                             leave_a_copy: leave_a_copy,
+                         })?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => folders::wait_for_job(self, r.body),
+            ResponseWithErr::Err(e) => Err(ErrorKind::UnshareFolderErr(e).into()),
+        }
+    }
+
+    /// The current user relinquishes their membership in the designated shared folder and will
+    /// no longer have access to the folder. A folder owner cannot relinquish membership in their
+    /// own folder. Blocks until the membership is relinquished.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-relinquish_folder_membership)
+    pub fn relinquish_folder_membership(&self,
+                                        shared_folder_id: &str,
+                                        leave_a_copy: bool)
+                                        -> Result<()> {
+        let url = Url::parse(BASE_URL)?.join("relinquish_folder_membership")?;
+        let resp_w_err: ResponseWithErr<LaunchEmptyResult, _> = self.rpc_request(url,
+                         &RelinquishFolderMembershipArg {
+                             shared_folder_id: shared_folder_id.to_owned(),
+                             leave_a_copy: leave_a_copy,
+                         })?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => folders::wait_for_job(self, r.body),
+            ResponseWithErr::Err(e) => Err(ErrorKind::RelinquishFolderMembershipErr(e).into()),
+        }
+    }
+
+    /// Transfer ownership of a shared folder to a member of the shared folder. User must have
+    /// AccessLevel.owner access to the shared folder to perform a transfer.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-transfer_folder)
+    pub fn transfer_folder(&self,
+                           shared_folder_id: &str,
+                           to_dropbox_id: &str)
+                           -> Result<Response<()>> {
+        let url = Url::parse(BASE_URL)?.join("transfer_folder")?;
+        let resp_w_err = self.rpc_request(url,
+                         &TransferFolderArg {
+                             shared_folder_id: shared_folder_id.to_owned(),
+                             to_dropbox_id: to_dropbox_id.to_owned(),
+                         })?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::TransferFolderErr(e).into()),
+        }
+    }
+
+    /// Update the sharing policies for a shared folder. User must have AccessLevel.owner access
+    /// to the shared folder to update its policies.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-update_folder_policy)
+    pub fn update_folder_policy(&self,
+                                shared_folder_id: &str)
+                                -> UpdateFolderPolicyRequestBuilder<Sharing> {
+        UpdateFolderPolicyRequestBuilder::new(self, shared_folder_id)
+    }
+
+    /// Returns shared folder metadata by its folder ID.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-get_folder_metadata)
+    pub fn get_folder_metadata(&self,
+                               shared_folder_id: &str)
+                               -> Result<Response<SharedFolderMetadata>> {
+        let url = Url::parse(BASE_URL)?.join("get_folder_metadata")?;
+        let resp_w_err = self.rpc_request(url,
+                         &SharedFolderArg { shared_folder_id: shared_folder_id.to_owned() })?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::GetFolderMetadataErr(e).into()),
+        }
+    }
+
+    /// Return the list of all shared folders the current user has access to.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-list_folders)
+    pub fn list_folders(&self) -> ListFoldersRequestBuilder<Sharing> {
+        ListFoldersRequestBuilder::new(self, FolderListing::Joined)
+    }
+
+    /// Once a cursor has been retrieved from list_folders, use this to paginate through all
+    /// shared folders.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-list_folders-continue)
+    pub fn list_folders_continue(&self, cursor: &str) -> Result<Response<ListFoldersResult>> {
+        folders::list_folders_continue(self, FolderListing::Joined, cursor)
+    }
+
+    /// Return the list of all shared folders the current user can mount or unmount.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-list_mountable_folders)
+    pub fn list_mountable_folders(&self) -> ListFoldersRequestBuilder<Sharing> {
+        ListFoldersRequestBuilder::new(self, FolderListing::Mountable)
+    }
+
+    /// Once a cursor has been retrieved from list_mountable_folders, use this to paginate
+    /// through all mountable shared folders.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-list_mountable_folders-continue)
+    pub fn list_mountable_folders_continue(&self,
+                                           cursor: &str)
+                                           -> Result<Response<ListFoldersResult>> {
+        folders::list_folders_continue(self, FolderListing::Mountable, cursor)
+    }
+
+    /// Allows an owner or editor (if the ACL update policy allows) of a shared folder to add
+    /// another member.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-add_folder_member)
+    pub fn add_folder_member(&self,
+                             shared_folder_id: &str)
+                             -> AddFolderMemberRequestBuilder<Sharing> {
+        AddFolderMemberRequestBuilder::new(self, shared_folder_id)
+    }
+
+    /// Allows an owner or editor (if the ACL update policy allows) of a shared folder to remove
+    /// another member. Blocks until the member is removed and returns the access it keeps
+    /// through a parent folder, if any.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-remove_folder_member)
+    pub fn remove_folder_member(&self,
+                                shared_folder_id: &str,
+                                member: &MemberSelector,
+                                leave_a_copy: bool)
+                                -> Result<MemberAccessLevelResult> {
+        members::remove_folder_member(self,
+                                      &RemoveFolderMemberArg {
+                                          shared_folder_id: shared_folder_id.to_owned(),
+                                          member: member.clone(),
+                                          leave_a_copy: leave_a_copy,
+                                      },
+                                      PollOptions::default())
+    }
+
+    /// Allows an owner or editor of a shared folder to update another member's permissions.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-update_folder_member)
+    pub fn update_folder_member(&self,
+                                shared_folder_id: &str,
+                                member: &MemberSelector,
+                                access_level: AccessLevel)
+                                -> Result<Response<MemberAccessLevelResult>> {
+        members::update_folder_member(self,
+                                      &UpdateFolderMemberArg {
+                                          shared_folder_id: shared_folder_id.to_owned(),
+                                          member: member.clone(),
+                                          access_level: access_level,
+                                      })
+    }
+
+    /// Returns shared folder membership by its folder ID.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-list_folder_members)
+    pub fn list_folder_members(&self,
+                               shared_folder_id: &str)
+                               -> ListFolderMembersRequestBuilder<Sharing> {
+        ListFolderMembersRequestBuilder::new(self, shared_folder_id)
+    }
+
+    /// Once a cursor has been retrieved from list_folder_members, use this to paginate through
+    /// all shared folder members.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-list_folder_members-continue)
+    pub fn list_folder_members_continue(&self, cursor: &str) -> Result<Response<SharedMembers>> {
+        members::list_folder_members_continue(self, cursor)
+    }
+
+    /// Makes the explicit members of a shared folder exactly the ones in `desired`, with their
+    /// access level. Only the members which differ are added, updated or removed, and the
+    /// applied changes are returned. See `members::membership_changes` for the members left
+    /// untouched.
+    pub fn set_folder_members(&self,
+                              shared_folder_id: &str,
+                              desired: &[(MemberSelector, AccessLevel)])
+                              -> Result<MembershipChanges> {
+        let mut current = SharedMembers {
+            users: Vec::new(),
+            groups: Vec::new(),
+            invitees: Vec::new(),
+            cursor: None,
+        };
+        for member in self.list_folder_members(shared_folder_id).iter() {
+            match member? {
+                MembershipInfo::User(user) => current.users.push(user),
+                MembershipInfo::Group(group) => current.groups.push(group),
+                MembershipInfo::Invitee(invitee) => current.invitees.push(invitee),
+            }
+        }
+        let changes = members::membership_changes(&current, desired);
+
+        if !changes.added.is_empty() {
+            members::add_folder_member(self,
+                                       &AddFolderMemberArg {
+                                           shared_folder_id: shared_folder_id.to_owned(),
+                                           members: changes.added
+                                               .iter()
+                                               .map(|&(ref member, access_level)| {
+                                                   AddMember {
+                                                       member: member.clone(),
+                                                       access_level: access_level,
+                                                   }
+                                               })
+                                               .collect(),
+                                           quiet: false,
+                                           custom_message: None,
+                                       })?;
+        }
+        for &(ref member, access_level) in &changes.updated {
+            self.update_folder_member(shared_folder_id, member, access_level)?;
+        }
+        for member in &changes.removed {
+            self.remove_folder_member(shared_folder_id, member, false)?;
+        }
+        Ok(changes)
+    }
+
+    /// Adds specified members to a file.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-add_file_member)
+    pub fn add_file_member(&self, file: &str) -> AddFileMemberRequestBuilder<Sharing> {
+        AddFileMemberRequestBuilder::new(self, file)
+    }
+
+    /// Removes a specified member from the file.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-remove_file_member_2)
+    pub fn remove_file_member_2(&self,
+                                file: &str,
+                                member: &MemberSelector)
+                                -> Result<Response<FileMemberRemoveActionResult>> {
+        let url = Url::parse(BASE_URL)?.join("remove_file_member_2")?;
+        let resp_w_err = self.rpc_request(url,
+                         &RemoveFileMemberArg {
+                             file: file.to_owned(),
+                             member: member.clone(),
+                         })?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::RemoveFileMemberErr(e).into()),
+        }
+    }
+
+    /// Use to obtain the members who have been invited to a file, both inherited and
+    /// uninherited members.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-list_file_members)
+    pub fn list_file_members(&self, file: &str) -> ListFileMembersRequestBuilder<Sharing> {
+        ListFileMembersRequestBuilder::new(self, file)
+    }
+
+    /// Once a cursor has been retrieved from list_file_members, use this to paginate through all
+    /// shared file members.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-list_file_members-continue)
+    pub fn list_file_members_continue(&self, cursor: &str) -> Result<Response<SharedMembers>> {
+        members::list_file_members_continue(self, cursor)
+    }
+
+    /// Get members of multiple files at once, up to `limit` members per file. The files may be
+    /// paths or ids and at most 100 files can be given.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#sharing-list_file_members-batch)
+    pub fn list_file_members_batch(&self,
+                                   files: &[&str],
+                                   limit: u32)
+                                   -> Result<Response<Vec<ListFileMembersBatchResult>>> {
+        members::list_file_members_batch(self,
+                                         &ListFileMembersBatchArg {
+                                             files: files.iter().map(|x| (*x).to_owned()).collect(),
+                                             limit: limit,
+                                         })
+    }
+}
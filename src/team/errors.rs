@@ -0,0 +1,192 @@
+use http::errors::APIError;
+use http::async_job::PollError;
+
+error_chain!{
+    links {
+        Http(::http::errors::Error, ::http::errors::ErrorKind);
+    }
+    foreign_links {
+        Url(::reqwest::UrlError);
+        Reqwest(::reqwest::Error);
+        Utf8(::std::string::FromUtf8Error);
+        Io(::std::io::Error);
+        Json(::serde_json::Error);
+        UrlEncodedSer(::serde_urlencoded::ser::Error);
+    }
+    errors {
+        MembersListErr(error: APIError<MembersListError>) {
+            description("MembersListError"),
+            display("{:?}", error)
+        }
+        MembersListContinueErr(error: APIError<MembersListContinueError>) {
+            description("MembersListContinueError"),
+            display("{:?}", error)
+        }
+        MembersGetInfoErr(error: APIError<MembersGetInfoError>) {
+            description("MembersGetInfoError"),
+            display("{:?}", error)
+        }
+        MembersAddFailed(error: String) {
+            description("The members/add_v2 job failed"),
+            display("The members/add_v2 job failed: {}", error)
+        }
+        MembersSuspendErr(error: APIError<MembersSuspendError>) {
+            description("MembersSuspendError"),
+            display("{:?}", error)
+        }
+        MembersUnsuspendErr(error: APIError<MembersUnsuspendError>) {
+            description("MembersUnsuspendError"),
+            display("{:?}", error)
+        }
+        MembersRemoveErr(error: APIError<MembersRemoveError>) {
+            description("MembersRemoveError"),
+            display("{:?}", error)
+        }
+        MembersSetProfileErr(error: APIError<MembersSetProfileError>) {
+            description("MembersSetProfileError"),
+            display("{:?}", error)
+        }
+        PollErr(error: APIError<PollError>) {
+            description("PollError"),
+            display("{:?}", error)
+        }
+    }
+}
+
+impl From<APIError<MembersListError>> for ErrorKind {
+    fn from(error: APIError<MembersListError>) -> Self {
+        ErrorKind::MembersListErr(error)
+    }
+}
+
+impl From<APIError<MembersListContinueError>> for ErrorKind {
+    fn from(error: APIError<MembersListContinueError>) -> Self {
+        ErrorKind::MembersListContinueErr(error)
+    }
+}
+
+impl From<APIError<MembersGetInfoError>> for ErrorKind {
+    fn from(error: APIError<MembersGetInfoError>) -> Self {
+        ErrorKind::MembersGetInfoErr(error)
+    }
+}
+
+impl From<APIError<MembersSuspendError>> for ErrorKind {
+    fn from(error: APIError<MembersSuspendError>) -> Self {
+        ErrorKind::MembersSuspendErr(error)
+    }
+}
+
+impl From<APIError<MembersUnsuspendError>> for ErrorKind {
+    fn from(error: APIError<MembersUnsuspendError>) -> Self {
+        ErrorKind::MembersUnsuspendErr(error)
+    }
+}
+
+impl From<APIError<MembersRemoveError>> for ErrorKind {
+    fn from(error: APIError<MembersRemoveError>) -> Self {
+        ErrorKind::MembersRemoveErr(error)
+    }
+}
+
+impl From<APIError<MembersSetProfileError>> for ErrorKind {
+    fn from(error: APIError<MembersSetProfileError>) -> Self {
+        ErrorKind::MembersSetProfileErr(error)
+    }
+}
+
+impl From<APIError<PollError>> for ErrorKind {
+    fn from(error: APIError<PollError>) -> Self {
+        ErrorKind::PollErr(error)
+    }
+}
+
+/// members/list_v2 only fails with errors Dropbox hasn't documented yet.
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum MembersListError {
+    Other,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum MembersListContinueError {
+    InvalidCursor,
+    Other,
+}
+
+/// Unknown members are returned as `MembersGetInfoItemV2::IdNotFound` rather than as an error.
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum MembersGetInfoError {
+    Other,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum MembersSuspendError {
+    UserNotFound,
+    UserNotInTeam,
+    SuspendInactiveUser,
+    /// The last admin of the team can't be suspended.
+    SuspendLastAdmin,
+    TeamLicenseLimit,
+    Other,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum MembersUnsuspendError {
+    UserNotFound,
+    UserNotInTeam,
+    UnsuspendNonSuspendedMember,
+    TeamLicenseLimit,
+    Other,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum MembersRemoveError {
+    UserNotFound,
+    UserNotInTeam,
+    RemoveLastAdmin,
+    RemovedAndTransferDestShouldDiffer,
+    RemovedAndTransferAdminShouldDiffer,
+    TransferDestUserNotFound,
+    TransferDestUserNotInTeam,
+    TransferAdminUserNotInTeam,
+    TransferAdminUserNotFound,
+    /// A transfer admin is needed when the files are transferred to another member.
+    UnspecifiedTransferAdminId,
+    TransferAdminIsNotAdmin,
+    RecipientNotVerified,
+    RemoveLastManualAdmin,
+    CannotKeepAccountAndTransfer,
+    CannotKeepAccountAndDeleteData,
+    EmailAddressTooLongToBeDisabled,
+    CannotKeepInvitedUserAccount,
+    CannotRetainSharesWhenDataWiped,
+    CannotRetainSharesWhenNoAccountKept,
+    CannotRetainSharesWhenTeamExternalSharingOff,
+    CannotKeepAccount,
+    CannotKeepAccountUnderLegalHold,
+    CannotKeepAccountRequiredToSignTos,
+    Other,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum MembersSetProfileError {
+    UserNotFound,
+    UserNotInTeam,
+    ExternalIdAndNewExternalIdUnsafe,
+    NoNewDataSpecified,
+    EmailReservedForOtherUser,
+    ExternalIdUsedByOtherUser,
+    SetProfileDisallowed,
+    ParamCannotBeEmpty,
+    PersistentIdDisabled,
+    PersistentIdUsedByOtherUser,
+    DirectoryRestrictedOff,
+    Other,
+}
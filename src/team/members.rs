@@ -0,0 +1,780 @@
+use reqwest::Url;
+
+use std::time::Duration;
+
+use team::errors::*;
+use users::Name;
+use http::{Response, ResponseWithErr};
+use http::RPCClient;
+use http::async_job::{AsyncJob, AsyncJobStatus, LaunchEmptyResult, LaunchResult, PollEmptyResult,
+                      PollError, PollOptions};
+use http::pagination::{Page, Paginated, PaginatedIter};
+
+/// The most members Dropbox returns per page of members/list_v2.
+pub const MAX_MEMBERS_LIST_LIMIT: u32 = 1000;
+
+/**
+ * team members
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum UserSelectorArg {
+    TeamMemberId { team_member_id: String },
+    ExternalId { external_id: String },
+    Email { email: String },
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+pub struct RemovedStatus {
+    /// Whether the member can still be recovered with members/recover.
+    pub is_recoverable: bool,
+    pub is_disconnected: bool,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum TeamMemberStatus {
+    Active,
+    /// The user was invited but hasn't joined the team yet.
+    Invited,
+    Suspended,
+    Removed(RemovedStatus),
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum TeamMembershipType {
+    /// Counts towards the team's licenses.
+    Full,
+    Limited,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct TeamMemberProfile {
+    pub team_member_id: String,
+    pub email: String,
+    pub email_verified: bool,
+    pub status: TeamMemberStatus,
+    pub name: Name,
+    pub membership_type: TeamMembershipType,
+    /// The ids of the groups the member belongs to.
+    pub groups: Vec<String>,
+    pub member_folder_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    /// Only set once the member has joined the team.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invited_on: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub joined_on: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suspended_on: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub persistent_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_directory_restricted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_photo_url: Option<String>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct TeamMemberRole {
+    pub role_id: String,
+    pub name: String,
+    pub description: String,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct TeamMemberInfoV2 {
+    pub profile: TeamMemberProfile,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub roles: Option<Vec<TeamMemberRole>>,
+}
+
+/**
+ * members/list_v2
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct MembersListArg {
+    pub limit: u32,
+    pub include_removed: bool,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct MembersListContinueArg {
+    pub cursor: String,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct MembersListV2Result {
+    pub members: Vec<TeamMemberInfoV2>,
+    pub cursor: String,
+    pub has_more: bool,
+}
+
+pub struct MembersListRequestBuilder<T> {
+    client: T,
+    arg: MembersListArg,
+}
+
+impl<T> MembersListRequestBuilder<T>
+    where T: RPCClient + Clone
+{
+    /// Defaults to pages of 1000 members, the most Dropbox returns at once, without the removed
+    /// members.
+    pub fn new(client: &T) -> MembersListRequestBuilder<T> {
+        MembersListRequestBuilder {
+            client: client.clone(),
+            arg: MembersListArg {
+                limit: MAX_MEMBERS_LIST_LIMIT,
+                include_removed: false,
+            },
+        }
+    }
+
+    /// The maximum number of members per page, up to 1000.
+    pub fn limit(&mut self, limit: u32) -> &mut MembersListRequestBuilder<T> {
+        self.arg.limit = limit;
+        self
+    }
+
+    pub fn include_removed(&mut self, include_removed: bool) -> &mut MembersListRequestBuilder<T> {
+        self.arg.include_removed = include_removed;
+        self
+    }
+
+    pub fn send(&self) -> Result<Response<MembersListV2Result>> {
+        members_list(&self.client, &self.arg)
+    }
+
+    /// Returns an iterator over every member, fetching the following pages as needed.
+    pub fn iter(&self) -> PaginatedIter<MembersListPages<T>> {
+        MembersListPages {
+                client: self.client.clone(),
+                arg: self.arg.clone(),
+            }
+            .iter_all()
+    }
+}
+
+/// The pages of members returned by members/list_v2.
+pub struct MembersListPages<T> {
+    pub client: T,
+    pub arg: MembersListArg,
+}
+
+impl<T: RPCClient> Paginated for MembersListPages<T> {
+    type Item = TeamMemberInfoV2;
+    type Error = Error;
+
+    fn first_page(&self) -> Result<Page<TeamMemberInfoV2>> {
+        Ok(members_page(members_list(&self.client, &self.arg)?.body))
+    }
+
+    fn next_page(&self, cursor: &str) -> Result<Page<TeamMemberInfoV2>> {
+        Ok(members_page(members_list_continue(&self.client, cursor)?.body))
+    }
+
+    fn item_key(&self, member: &TeamMemberInfoV2) -> String {
+        member.profile.team_member_id.clone()
+    }
+}
+
+fn members_page(result: MembersListV2Result) -> Page<TeamMemberInfoV2> {
+    Page {
+        items: result.members,
+        cursor: result.cursor,
+        cursor_expiration: None,
+        has_more: result.has_more,
+    }
+}
+
+pub fn members_list<T: RPCClient>(client: &T,
+                                  arg: &MembersListArg)
+                                  -> Result<Response<MembersListV2Result>> {
+    let url = Url::parse(super::BASE_URL)?.join("members/list_v2")?;
+    let resp_w_err = client.rpc_request(url, arg)?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::MembersListErr(e).into()),
+    }
+}
+
+pub fn members_list_continue<T: RPCClient>(client: &T,
+                                           cursor: &str)
+                                           -> Result<Response<MembersListV2Result>> {
+    let url = Url::parse(super::BASE_URL)?.join("members/list/continue_v2")?;
+    let resp_w_err = client.rpc_request(url,
+                     &MembersListContinueArg { cursor: cursor.to_owned() })?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::MembersListContinueErr(e).into()),
+    }
+}
+
+/**
+ * members/get_info_v2
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct MembersGetInfoV2Arg {
+    pub members: Vec<UserSelectorArg>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum MembersGetInfoItemV2 {
+    /// The value is the selected id or email which didn't match any member.
+    IdNotFound { id_not_found: String },
+    MemberInfo(TeamMemberInfoV2),
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct MembersGetInfoV2Result {
+    /// In the same order as the requested members.
+    pub members_info: Vec<MembersGetInfoItemV2>,
+}
+
+/**
+ * members/add_v2
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct MemberAddV2Arg {
+    pub member_email: String,
+    pub send_welcome_email: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_given_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_surname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_external_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_persistent_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_directory_restricted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role_ids: Option<Vec<String>>,
+}
+
+impl MemberAddV2Arg {
+    /// Sends the welcome email, as the Dropbox API does by default.
+    pub fn new(member_email: &str) -> MemberAddV2Arg {
+        MemberAddV2Arg {
+            member_email: member_email.to_owned(),
+            send_welcome_email: true,
+            member_given_name: None,
+            member_surname: None,
+            member_external_id: None,
+            member_persistent_id: None,
+            is_directory_restricted: None,
+            role_ids: None,
+        }
+    }
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct MembersAddV2Arg {
+    pub new_members: Vec<MemberAddV2Arg>,
+    pub force_async: bool,
+}
+
+/// The outcome of adding a member. Apart from `Success`, the value of each variant is the email
+/// of the member which couldn't be added.
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum MemberAddV2Result {
+    Success(TeamMemberInfoV2),
+    TeamLicenseLimit { team_license_limit: String },
+    FreeTeamMemberLimitReached { free_team_member_limit_reached: String },
+    UserAlreadyOnTeam { user_already_on_team: String },
+    UserOnAnotherTeam { user_on_another_team: String },
+    UserAlreadyPaired { user_already_paired: String },
+    UserMigrationFailed { user_migration_failed: String },
+    DuplicateExternalMemberId { duplicate_external_member_id: String },
+    DuplicateMemberPersistentId { duplicate_member_persistent_id: String },
+    PersistentIdDisabled { persistent_id_disabled: String },
+    UserCreationFailed { user_creation_failed: String },
+}
+
+/// The `complete` value of members/add_v2 and of its job status, a list of results in the same
+/// order as the added members.
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct MembersAddResults {
+    pub complete: Vec<MemberAddV2Result>,
+}
+
+pub struct MembersAddRequestBuilder<T> {
+    client: T,
+    arg: MembersAddV2Arg,
+    send_welcome_email: Option<bool>,
+    poll_options: PollOptions,
+}
+
+impl<T> MembersAddRequestBuilder<T>
+    where T: RPCClient + Clone
+{
+    pub fn new(client: &T) -> MembersAddRequestBuilder<T> {
+        MembersAddRequestBuilder {
+            client: client.clone(),
+            arg: MembersAddV2Arg {
+                new_members: Vec::new(),
+                force_async: false,
+            },
+            send_welcome_email: None,
+            poll_options: PollOptions::default(),
+        }
+    }
+
+    /// Queues a member to add. Build it with `MemberAddV2Arg::new` and set its optional fields,
+    /// like the member's name or external id, directly on the struct. All queued members are added
+    /// by a single members/add_v2 call.
+    pub fn add_member(&mut self, member: &MemberAddV2Arg) -> &mut MembersAddRequestBuilder<T> {
+        self.arg.new_members.push(member.clone());
+        self
+    }
+
+    /// Overrides `send_welcome_email` of every member added through this builder.
+    pub fn send_welcome_email(&mut self, send_welcome_email: bool)
+                              -> &mut MembersAddRequestBuilder<T> {
+        self.send_welcome_email = Some(send_welcome_email);
+        self
+    }
+
+    /// Always adds the members through an async job, even when it could be done right away.
+    pub fn force_async(&mut self, force_async: bool) -> &mut MembersAddRequestBuilder<T> {
+        self.arg.force_async = force_async;
+        self
+    }
+
+    pub fn poll_interval(&mut self, interval: Duration) -> &mut MembersAddRequestBuilder<T> {
+        self.poll_options.interval = interval;
+        self
+    }
+
+    pub fn max_polls(&mut self, max_polls: u32) -> &mut MembersAddRequestBuilder<T> {
+        self.poll_options.max_polls = Some(max_polls);
+        self
+    }
+
+    /// Adds the members and blocks until Dropbox finished adding them, returning the outcome for
+    /// each member in the order they were added to this builder. Nothing is sent when no member
+    /// was added.
+    pub fn send(&self) -> Result<Vec<MemberAddV2Result>> {
+        if self.arg.new_members.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut arg = self.arg.clone();
+        if let Some(send_welcome_email) = self.send_welcome_email {
+            for member in &mut arg.new_members {
+                member.send_welcome_email = send_welcome_email;
+            }
+        }
+
+        let url = Url::parse(super::BASE_URL)?.join("members/add_v2")?;
+        let resp_w_err: ResponseWithErr<LaunchResult<MembersAddResults>, ()> =
+            self.client.rpc_request(url, &arg)?;
+        let launch = match resp_w_err {
+            ResponseWithErr::Ok(r) => r.body,
+            ResponseWithErr::Err(_) => unreachable!("team: members/add_v2 has no error"),
+        };
+
+        match launch {
+            LaunchResult::Complete(results) => Ok(results.complete),
+            LaunchResult::AsyncJobId { async_job_id } => {
+                let check_url = Url::parse(super::BASE_URL)?.join("members/add/job_status/get_v2")?;
+                let status: ResponseWithErr<AsyncJobStatus<MembersAddResults, String>,
+                                            PollError> =
+                    AsyncJob::new(&self.client, check_url, &async_job_id)
+                        .options(self.poll_options)
+                        .wait()?;
+                match status {
+                    ResponseWithErr::Ok(Response { body: AsyncJobStatus::Complete(results), .. }) => {
+                        Ok(results.complete)
+                    }
+                    ResponseWithErr::Ok(Response { body: AsyncJobStatus::Failed { failed }, .. }) => {
+                        Err(ErrorKind::MembersAddFailed(failed).into())
+                    }
+                    ResponseWithErr::Ok(_) => unreachable!("team: members/add_v2 job in progress"),
+                    ResponseWithErr::Err(e) => Err(ErrorKind::PollErr(e).into()),
+                }
+            }
+        }
+    }
+}
+
+/**
+ * members/suspend and members/unsuspend
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct MembersDeactivateArg {
+    pub user: UserSelectorArg,
+    /// Wipes the member's data from their linked devices.
+    pub wipe_data: bool,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct MembersUnsuspendArg {
+    pub user: UserSelectorArg,
+}
+
+/**
+ * members/remove
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct MembersRemoveArg {
+    pub user: UserSelectorArg,
+    pub wipe_data: bool,
+    pub keep_account: bool,
+    pub retain_team_shares: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_dest_id: Option<UserSelectorArg>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_admin_id: Option<UserSelectorArg>,
+}
+
+pub struct MembersRemoveRequestBuilder<T> {
+    client: T,
+    arg: MembersRemoveArg,
+    poll_options: PollOptions,
+}
+
+impl<T> MembersRemoveRequestBuilder<T>
+    where T: RPCClient + Clone
+{
+    /// Defaults to wiping the member's data from their devices without keeping their account, as
+    /// the Dropbox API does.
+    pub fn new(client: &T, user: &UserSelectorArg) -> MembersRemoveRequestBuilder<T> {
+        MembersRemoveRequestBuilder {
+            client: client.clone(),
+            arg: MembersRemoveArg {
+                user: user.clone(),
+                wipe_data: true,
+                keep_account: false,
+                retain_team_shares: false,
+                transfer_dest_id: None,
+                transfer_admin_id: None,
+            },
+            poll_options: PollOptions::default(),
+        }
+    }
+
+    pub fn wipe_data(&mut self, wipe_data: bool) -> &mut MembersRemoveRequestBuilder<T> {
+        self.arg.wipe_data = wipe_data;
+        self
+    }
+
+    /// Transfers the files of the removed member to `transfer_dest_id`. `transfer_admin_id` is
+    /// notified of any errors during the transfer.
+    pub fn transfer_to(&mut self,
+                       transfer_dest_id: &UserSelectorArg,
+                       transfer_admin_id: &UserSelectorArg)
+                       -> &mut MembersRemoveRequestBuilder<T> {
+        self.arg.transfer_dest_id = Some(transfer_dest_id.clone());
+        self.arg.transfer_admin_id = Some(transfer_admin_id.clone());
+        self
+    }
+
+    /// Turns the member's account into a Basic account instead of deleting it. Can't be combined
+    /// with `wipe_data` or a transfer.
+    pub fn keep_account(&mut self, keep_account: bool) -> &mut MembersRemoveRequestBuilder<T> {
+        self.arg.keep_account = keep_account;
+        self
+    }
+
+    /// Keeps the member's access to the folders shared with them through the team. Needs
+    /// `keep_account`.
+    pub fn retain_team_shares(&mut self,
+                              retain_team_shares: bool)
+                              -> &mut MembersRemoveRequestBuilder<T> {
+        self.arg.retain_team_shares = retain_team_shares;
+        self
+    }
+
+    pub fn poll_interval(&mut self, interval: Duration) -> &mut MembersRemoveRequestBuilder<T> {
+        self.poll_options.interval = interval;
+        self
+    }
+
+    pub fn max_polls(&mut self, max_polls: u32) -> &mut MembersRemoveRequestBuilder<T> {
+        self.poll_options.max_polls = Some(max_polls);
+        self
+    }
+
+    /// Removes the member and blocks until Dropbox finished removing it.
+    pub fn send(&self) -> Result<()> {
+        let url = Url::parse(super::BASE_URL)?.join("members/remove")?;
+        let resp_w_err: ResponseWithErr<LaunchEmptyResult, _> =
+            self.client.rpc_request(url, &self.arg)?;
+        let launch = match resp_w_err {
+            ResponseWithErr::Ok(r) => r.body,
+            ResponseWithErr::Err(e) => return Err(ErrorKind::MembersRemoveErr(e).into()),
+        };
+        let async_job_id = match launch {
+            LaunchEmptyResult::Complete => return Ok(()),
+            LaunchEmptyResult::AsyncJobId { async_job_id } => async_job_id,
+        };
+
+        let check_url = Url::parse(super::BASE_URL)?.join("members/remove/job_status/get")?;
+        let status: ResponseWithErr<PollEmptyResult<()>, PollError> =
+            AsyncJob::new(&self.client, check_url, &async_job_id)
+                .options(self.poll_options)
+                .wait()?;
+        match status {
+            ResponseWithErr::Ok(Response { body: PollEmptyResult::Complete, .. }) => Ok(()),
+            ResponseWithErr::Ok(_) => unreachable!("team: members/remove jobs only complete"),
+            ResponseWithErr::Err(e) => Err(ErrorKind::PollErr(e).into()),
+        }
+    }
+}
+
+/**
+ * members/set_profile_v2
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct MembersSetProfileArg {
+    pub user: UserSelectorArg,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_external_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_given_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_surname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_persistent_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_is_directory_restricted: Option<bool>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct TeamMemberInfoV2Result {
+    pub member_info: TeamMemberInfoV2,
+}
+
+pub struct MembersSetProfileRequestBuilder<T> {
+    client: T,
+    arg: MembersSetProfileArg,
+}
+
+impl<T> MembersSetProfileRequestBuilder<T>
+    where T: RPCClient + Clone
+{
+    /// Fields which aren't set are left unchanged.
+    pub fn new(client: &T, user: &UserSelectorArg) -> MembersSetProfileRequestBuilder<T> {
+        MembersSetProfileRequestBuilder {
+            client: client.clone(),
+            arg: MembersSetProfileArg {
+                user: user.clone(),
+                new_email: None,
+                new_external_id: None,
+                new_given_name: None,
+                new_surname: None,
+                new_persistent_id: None,
+                new_is_directory_restricted: None,
+            },
+        }
+    }
+
+    pub fn email(&mut self, email: &str) -> &mut MembersSetProfileRequestBuilder<T> {
+        self.arg.new_email = Some(email.to_owned());
+        self
+    }
+
+    pub fn external_id(&mut self, external_id: &str) -> &mut MembersSetProfileRequestBuilder<T> {
+        self.arg.new_external_id = Some(external_id.to_owned());
+        self
+    }
+
+    pub fn given_name(&mut self, given_name: &str) -> &mut MembersSetProfileRequestBuilder<T> {
+        self.arg.new_given_name = Some(given_name.to_owned());
+        self
+    }
+
+    pub fn surname(&mut self, surname: &str) -> &mut MembersSetProfileRequestBuilder<T> {
+        self.arg.new_surname = Some(surname.to_owned());
+        self
+    }
+
+    pub fn persistent_id(&mut self,
+                         persistent_id: &str)
+                         -> &mut MembersSetProfileRequestBuilder<T> {
+        self.arg.new_persistent_id = Some(persistent_id.to_owned());
+        self
+    }
+
+    pub fn is_directory_restricted(&mut self,
+                                   is_directory_restricted: bool)
+                                   -> &mut MembersSetProfileRequestBuilder<T> {
+        self.arg.new_is_directory_restricted = Some(is_directory_restricted);
+        self
+    }
+
+    pub fn send(&self) -> Result<Response<TeamMemberInfoV2Result>> {
+        let url = Url::parse(super::BASE_URL)?.join("members/set_profile_v2")?;
+        let resp_w_err = self.client.rpc_request(url, &self.arg)?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::MembersSetProfileErr(e).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use reqwest::StatusCode;
+    use reqwest::header::Headers;
+    use serde::Serialize;
+    use serde::de::DeserializeOwned;
+    use serde_json::{self, Value};
+
+    use http;
+    use super::*;
+
+    /// Records the bodies of the requests and answers members/add_v2 with no results.
+    #[derive(Clone)]
+    struct FakeTeam {
+        requests: Rc<RefCell<Vec<Value>>>,
+    }
+
+    impl RPCClient for FakeTeam {
+        fn rpc_request<T, R, E>(&self,
+                                _url: Url,
+                                request_body: T)
+                                -> http::errors::Result<ResponseWithErr<R, E>>
+            where T: Serialize,
+                  R: DeserializeOwned,
+                  E: DeserializeOwned
+        {
+            self.requests.borrow_mut().push(serde_json::to_value(&request_body)?);
+            Ok(ResponseWithErr::Ok(Response {
+                body: serde_json::from_str(r#"{".tag": "complete", "complete": []}"#)?,
+                status: StatusCode::Ok,
+                headers: Headers::new(),
+            }))
+        }
+    }
+
+    fn round_trip<T: Serialize + DeserializeOwned>(json: &str) -> T {
+        let value: Value = serde_json::from_str(json).expect("error parsing json");
+        let deserialized: T = serde_json::from_value(value.clone()).expect("error deserializing");
+        assert_eq!(serde_json::to_value(&deserialized).expect("error serializing"), value);
+        deserialized
+    }
+
+    const ADD_RESULTS: &'static str = r#"{
+        ".tag": "complete",
+        "complete": [
+            {
+                ".tag": "success",
+                "profile": {
+                    "team_member_id": "dbmid:FDFSVF-DFSDF",
+                    "email": "tami@seagull.com",
+                    "email_verified": false,
+                    "status": {".tag": "active"},
+                    "name": {
+                        "given_name": "Franz",
+                        "surname": "Ferdinand",
+                        "familiar_name": "Franz",
+                        "display_name": "Franz Ferdinand (Personal)",
+                        "abbreviated_name": "FF"
+                    },
+                    "membership_type": {".tag": "full"},
+                    "groups": ["g:e2db7665347abcd600000000001a2b3c"],
+                    "member_folder_id": "20",
+                    "external_id": "244423",
+                    "account_id": "dbid:AAH4f99T0taONIb-OurWxbNQ6ywGRopQngc",
+                    "joined_on": "2015-05-12T15:50:38Z"
+                },
+                "roles": [{
+                    "role_id": "pid_dbtmr:3456",
+                    "name": "User management admin",
+                    "description": "Add, remove, and manage member accounts."
+                }]
+            },
+            {".tag": "user_already_on_team", "user_already_on_team": "jane@seagull.com"}
+        ]
+    }"#;
+
+    #[test]
+    fn test_members_add_launch_and_job_status() {
+        match round_trip::<LaunchResult<MembersAddResults>>(ADD_RESULTS) {
+            LaunchResult::Complete(results) => {
+                assert_eq!(results.complete[1],
+                           MemberAddV2Result::UserAlreadyOnTeam {
+                               user_already_on_team: "jane@seagull.com".to_owned(),
+                           });
+                match results.complete[0] {
+                    MemberAddV2Result::Success(ref info) => {
+                        assert_eq!(info.profile.status, TeamMemberStatus::Active)
+                    }
+                    ref result => panic!("unexpected result {:?}", result),
+                }
+            }
+            launch => panic!("unexpected launch result {:?}", launch),
+        }
+        match round_trip::<LaunchResult<MembersAddResults>>(r#"{
+            ".tag": "async_job_id",
+            "async_job_id": "dbjid:AAD0ybj8d64hj1Wx1Tv6lnCr1ByKzM"
+        }"#) {
+            LaunchResult::AsyncJobId { .. } => {}
+            launch => panic!("unexpected launch result {:?}", launch),
+        }
+
+        match round_trip::<AsyncJobStatus<MembersAddResults, String>>(ADD_RESULTS) {
+            AsyncJobStatus::Complete(ref results) => assert_eq!(results.complete.len(), 2),
+            ref status => panic!("unexpected job status {:?}", status),
+        }
+        match round_trip::<AsyncJobStatus<MembersAddResults, String>>(r#"{
+            ".tag": "failed",
+            "failed": "An internal error occurred"
+        }"#) {
+            AsyncJobStatus::Failed { .. } => {}
+            ref status => panic!("unexpected job status {:?}", status),
+        }
+    }
+
+    #[test]
+    fn test_members_add_send() {
+        let client = FakeTeam { requests: Rc::new(RefCell::new(Vec::new())) };
+
+        let results = MembersAddRequestBuilder::new(&client)
+            .send()
+            .expect("error adding no members");
+        assert!(results.is_empty());
+        assert!(client.requests.borrow().is_empty());
+
+        MembersAddRequestBuilder::new(&client)
+            .add_member(&MemberAddV2Arg::new("tami@seagull.com"))
+            .add_member(&MemberAddV2Arg::new("jane@seagull.com"))
+            .send_welcome_email(false)
+            .send()
+            .expect("error adding members");
+        let requests = client.requests.borrow();
+        assert_eq!(requests.len(), 1);
+        let new_members = requests[0]["new_members"].as_array().unwrap();
+        assert_eq!(new_members.len(), 2);
+        assert!(new_members.iter().all(|x| x["send_welcome_email"] == Value::Bool(false)));
+    }
+
+    #[test]
+    fn test_members_remove_launch_and_job_status() {
+        assert_eq!(round_trip::<LaunchEmptyResult>(r#"{".tag": "complete"}"#),
+                   LaunchEmptyResult::Complete);
+        assert_eq!(round_trip::<LaunchEmptyResult>(r#"{
+                       ".tag": "async_job_id",
+                       "async_job_id": "dbjid:AAD0ybj8d64hj1Wx1Tv6lnCr1ByKzM"
+                   }"#),
+                   LaunchEmptyResult::AsyncJobId {
+                       async_job_id: "dbjid:AAD0ybj8d64hj1Wx1Tv6lnCr1ByKzM".to_owned(),
+                   });
+        assert_eq!(round_trip::<PollEmptyResult<()>>(r#"{".tag": "complete"}"#),
+                   PollEmptyResult::Complete);
+        assert_eq!(round_trip::<PollEmptyResult<()>>(r#"{".tag": "in_progress"}"#),
+                   PollEmptyResult::InProgress);
+    }
+}
@@ -0,0 +1,175 @@
+//! [Dropbox Team Documentation](https://www.dropbox.com/developers/documentation/http/teams#team)
+//!
+//! This namespace contains endpoints and data types for managing a Dropbox Business team. Its
+//! endpoints must be called with a team access token.
+//!
+
+pub mod errors;
+pub mod members;
+
+use reqwest::Url;
+
+use std::rc::Rc;
+
+use self::errors::*;
+use self::members::*;
+use http::{Response, ResponseWithErr};
+use http::RPCClient;
+use users::{OfficeAddInPolicy, TeamSharingPolicies};
+
+static BASE_URL: &'static str = "https://api.dropboxapi.com/2/team/";
+
+/// A client to interface with the endpoints in the Team namespace of the Dropbox APIs
+#[derive(Debug,Clone)]
+pub struct Team {
+    access_token: Rc<String>,
+}
+
+impl ::http::HasAccessToken for Team {
+    fn access_token(&self) -> &str {
+        self.access_token.as_str()
+    }
+}
+
+impl Team {
+    pub fn new(access_token: Rc<String>) -> Team {
+        Team { access_token: Rc::clone(&access_token) }
+    }
+
+    /// Retrieves information about a team.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/teams#team-get_info)
+    pub fn get_info(&self) -> Result<Response<TeamGetInfoResult>> {
+        let url = Url::parse(BASE_URL)?.join("get_info")?;
+        let resp_w_err: ResponseWithErr<_, ()> = self.rpc_request(url, ())?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(_) => unreachable!("team: get_info has no error"),
+        }
+    }
+
+    /// Lists members of a team.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/teams#team-members-list)
+    pub fn members_list(&self) -> MembersListRequestBuilder<Team> {
+        MembersListRequestBuilder::new(self)
+    }
+
+    /// Once a cursor has been retrieved from members/list_v2, use this to paginate through all
+    /// team members.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/teams#team-members-list-continue)
+    pub fn members_list_continue(&self, cursor: &str) -> Result<Response<MembersListV2Result>> {
+        members_list_continue(self, cursor)
+    }
+
+    /// Returns information about multiple team members. The members which don't exist are
+    /// returned as `MembersGetInfoItemV2::IdNotFound`.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/teams#team-members-get_info)
+    pub fn members_get_info(&self,
+                            members: &[UserSelectorArg])
+                            -> Result<Response<MembersGetInfoV2Result>> {
+        let url = Url::parse(BASE_URL)?.join("members/get_info_v2")?;
+        let resp_w_err = self.rpc_request(url,
+                         &MembersGetInfoV2Arg { members: members.to_vec() })?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::MembersGetInfoErr(e).into()),
+        }
+    }
+
+    /// Adds members to a team. The members are added through an async job which `send` waits
+    /// upon.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/teams#team-members-add)
+    pub fn members_add(&self) -> MembersAddRequestBuilder<Team> {
+        MembersAddRequestBuilder::new(self)
+    }
+
+    /// Suspend a member from a team. `wipe_data` also removes the member's data from their
+    /// linked devices.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/teams#team-members-suspend)
+    pub fn members_suspend(&self, user: &UserSelectorArg, wipe_data: bool) -> Result<()> {
+        let url = Url::parse(BASE_URL)?.join("members/suspend")?;
+        let resp_w_err: ResponseWithErr<(), _> = self.rpc_request(url,
+                                                                  &MembersDeactivateArg {
+                                                                      user: user.clone(),
+                                                                      wipe_data: wipe_data,
+                                                                  })?;
+        match resp_w_err {
+            ResponseWithErr::Ok(_) => Ok(()),
+            ResponseWithErr::Err(e) => Err(ErrorKind::MembersSuspendErr(e).into()),
+        }
+    }
+
+    /// Unsuspend a member from a team.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/teams#team-members-unsuspend)
+    pub fn members_unsuspend(&self, user: &UserSelectorArg) -> Result<()> {
+        let url = Url::parse(BASE_URL)?.join("members/unsuspend")?;
+        let resp_w_err: ResponseWithErr<(), _> =
+            self.rpc_request(url, &MembersUnsuspendArg { user: user.clone() })?;
+        match resp_w_err {
+            ResponseWithErr::Ok(_) => Ok(()),
+            ResponseWithErr::Err(e) => Err(ErrorKind::MembersUnsuspendErr(e).into()),
+        }
+    }
+
+    /// Removes a member from a team. The member's files can be transferred to another member,
+    /// or their account can be kept as a Basic account.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/teams#team-members-remove)
+    pub fn members_remove(&self, user: &UserSelectorArg) -> MembersRemoveRequestBuilder<Team> {
+        MembersRemoveRequestBuilder::new(self, user)
+    }
+
+    /// Updates a team member's profile.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/teams#team-members-set_profile)
+    pub fn members_set_profile(&self,
+                               user: &UserSelectorArg)
+                               -> MembersSetProfileRequestBuilder<Team> {
+        MembersSetProfileRequestBuilder::new(self, user)
+    }
+}
+
+/**
+ * get_info
+ **/
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum EmmState {
+    Disabled,
+    Optional,
+    Required,
+    Other,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum SuggestMembersPolicy {
+    Disabled,
+    Enabled,
+    Other,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct TeamMemberPolicies {
+    pub sharing: TeamSharingPolicies,
+    pub emm_state: EmmState,
+    pub office_addin: OfficeAddInPolicy,
+    pub suggest_members_policy: SuggestMembersPolicy,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct TeamGetInfoResult {
+    pub name: String,
+    pub team_id: String,
+    pub num_licensed_users: u32,
+    pub num_provisioned_users: u32,
+    pub policies: TeamMemberPolicies,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_used_licenses: Option<u32>,
+}
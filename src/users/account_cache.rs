@@ -0,0 +1,177 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use users::errors::*;
+use users::{BasicAccount, MAX_ACCOUNT_BATCH_SIZE};
+use http::RPCClient;
+
+/// Caches the accounts fetched through get_account_batch so that each account is only fetched
+/// once. Clones share the same accounts.
+#[derive(Debug,Clone,Default)]
+pub struct AccountCache {
+    accounts: Rc<RefCell<HashMap<String, BasicAccount>>>,
+}
+
+impl AccountCache {
+    pub fn new() -> AccountCache {
+        AccountCache::default()
+    }
+
+    pub fn get(&self, account_id: &str) -> Option<BasicAccount> {
+        self.accounts.borrow().get(account_id).cloned()
+    }
+
+    pub fn len(&self) -> usize {
+        self.accounts.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.accounts.borrow().is_empty()
+    }
+
+    /// Returns the accounts of `account_ids`, fetching the ones which aren't cached yet in
+    /// batches of up to 300 accounts.
+    ///
+    /// The accounts which don't exist anymore, e.g. because they were deleted, are left out of
+    /// the returned accounts.
+    pub fn resolve<T: RPCClient>(&self,
+                                 client: &T,
+                                 account_ids: &[&str])
+                                 -> Result<HashMap<String, BasicAccount>> {
+        let mut missing = account_ids.iter()
+            .cloned()
+            .filter(|x| !self.accounts.borrow().contains_key(*x))
+            .collect::<Vec<_>>();
+        missing.sort();
+        missing.dedup();
+
+        for batch in missing.chunks(MAX_ACCOUNT_BATCH_SIZE) {
+            let mut batch = batch.to_vec();
+            while !batch.is_empty() {
+                match super::get_account_batch(client, &batch) {
+                    Ok(fetched) => {
+                        let mut accounts = self.accounts.borrow_mut();
+                        for account in fetched.body {
+                            accounts.insert(account.account_id.clone(), account);
+                        }
+                        break;
+                    }
+                    Err(e) => {
+                        // The whole batch fails on the first account which doesn't exist, retry
+                        // without it
+                        let no_account = match *e.kind() {
+                            ErrorKind::GetAccountBatchErr(ref api_error) => {
+                                match api_error.error {
+                                    GetAccountBatchError::NoAccount { ref no_account } => {
+                                        Some(no_account.clone())
+                                    }
+                                }
+                            }
+                            _ => None,
+                        };
+                        match no_account {
+                            Some(ref no_account) if batch.contains(&no_account.as_str()) => {
+                                warn!("[AccountCache] [account_id = {}] account not found",
+                                      no_account);
+                                batch.retain(|x| *x != no_account.as_str());
+                            }
+                            _ => return Err(e),
+                        }
+                    }
+                }
+            }
+        }
+
+        let accounts = self.accounts.borrow();
+        Ok(account_ids.iter()
+            .filter_map(|x| accounts.get(*x))
+            .map(|x| (x.account_id.clone(), x.clone()))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::{StatusCode, Url};
+    use reqwest::header::Headers;
+    use serde::Serialize;
+    use serde::de::DeserializeOwned;
+    use serde_json;
+
+    use std::cell::Cell;
+
+    use http::{self, Response, ResponseWithErr, RPCClient};
+    use http::errors::APIError;
+    use super::*;
+
+    /// Answers get_account_batch, failing with no_account for the ids in `deleted`.
+    struct FakeUsers {
+        deleted: Vec<&'static str>,
+        calls: Cell<u32>,
+    }
+
+    impl RPCClient for FakeUsers {
+        fn rpc_request<T, R, E>(&self,
+                                _url: Url,
+                                request_body: T)
+                                -> http::errors::Result<ResponseWithErr<R, E>>
+            where T: Serialize,
+                  R: DeserializeOwned,
+                  E: DeserializeOwned
+        {
+            self.calls.set(self.calls.get() + 1);
+            let arg: serde_json::Value = serde_json::to_value(&request_body)?;
+            let account_ids = arg["account_ids"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|x| x.as_str().unwrap().to_owned())
+                .collect::<Vec<_>>();
+
+            if let Some(id) = account_ids.iter().find(|x| self.deleted.contains(&x.as_str())) {
+                let error = format!(r#"{{"error_summary": "no_account/...",
+                                        "error": {{".tag": "no_account", "no_account": "{}"}}}}"#,
+                                    id);
+                return Ok(ResponseWithErr::Err(APIError::build_error(StatusCode::Conflict,
+                                                                     error)?));
+            }
+            let accounts = account_ids.iter()
+                .map(|id| {
+                    format!(r#"{{"account_id": "{0}", "email": "{0}@example.com",
+                                 "email_verified": true, "disabled": false,
+                                 "is_teammate": false,
+                                 "name": {{"given_name": "{0}", "surname": "{0}",
+                                           "familiar_name": "{0}", "display_name": "{0}",
+                                           "abbreviated_name": "{0}"}}}}"#,
+                            id)
+                })
+                .collect::<Vec<_>>();
+            Ok(ResponseWithErr::Ok(Response {
+                body: serde_json::from_str(&format!("[{}]", accounts.join(",")))?,
+                status: StatusCode::Ok,
+                headers: Headers::new(),
+            }))
+        }
+    }
+
+    #[test]
+    fn test_resolve_skips_deleted_accounts() {
+        let client = FakeUsers {
+            deleted: vec!["dbid:deleted1", "dbid:deleted2"],
+            calls: Cell::new(0),
+        };
+        let cache = AccountCache::new();
+
+        let accounts = cache.resolve(&client,
+                     &["dbid:a", "dbid:deleted1", "dbid:b", "dbid:deleted2"])
+            .expect("error resolving accounts");
+        assert_eq!(accounts.len(), 2);
+        assert_eq!(accounts["dbid:a"].email, "dbid:a@example.com");
+        assert!(!accounts.contains_key("dbid:deleted1"));
+        assert_eq!(client.calls.get(), 3);
+
+        cache.resolve(&client, &["dbid:a", "dbid:b"]).expect("error resolving cached accounts");
+        assert_eq!(client.calls.get(), 3);
+    }
+}
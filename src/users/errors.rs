@@ -0,0 +1,67 @@
+use http::errors::APIError;
+
+error_chain!{
+    links {
+        Http(::http::errors::Error, ::http::errors::ErrorKind);
+    }
+    foreign_links {
+        Url(::reqwest::UrlError);
+        Reqwest(::reqwest::Error);
+        Utf8(::std::string::FromUtf8Error);
+        Io(::std::io::Error);
+        Json(::serde_json::Error);
+        UrlEncodedSer(::serde_urlencoded::ser::Error);
+    }
+    errors {
+        GetAccountErr(error: APIError<GetAccountError>) {
+            description("GetAccountError"),
+            display("{:?}", error)
+        }
+        GetAccountBatchErr(error: APIError<GetAccountBatchError>) {
+            description("GetAccountBatchError"),
+            display("{:?}", error)
+        }
+        UserFeaturesGetValuesBatchErr(error: APIError<UserFeaturesGetValuesBatchError>) {
+            description("UserFeaturesGetValuesBatchError"),
+            display("{:?}", error)
+        }
+    }
+}
+
+impl From<APIError<GetAccountError>> for ErrorKind {
+    fn from(error: APIError<GetAccountError>) -> Self {
+        ErrorKind::GetAccountErr(error)
+    }
+}
+
+impl From<APIError<GetAccountBatchError>> for ErrorKind {
+    fn from(error: APIError<GetAccountBatchError>) -> Self {
+        ErrorKind::GetAccountBatchErr(error)
+    }
+}
+
+impl From<APIError<UserFeaturesGetValuesBatchError>> for ErrorKind {
+    fn from(error: APIError<UserFeaturesGetValuesBatchError>) -> Self {
+        ErrorKind::UserFeaturesGetValuesBatchErr(error)
+    }
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum GetAccountError {
+    /// The account id doesn't exist or the account was deleted.
+    NoAccount,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum GetAccountBatchError {
+    /// The value is the first account id which doesn't exist or was deleted.
+    NoAccount { no_account: String },
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum UserFeaturesGetValuesBatchError {
+    EmptyFeaturesList,
+}
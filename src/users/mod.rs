@@ -0,0 +1,340 @@
+//! [Dropbox Users Documentation](https://www.dropbox.com/developers/documentation/http/documentation#users)
+//!
+//! This namespace contains endpoints and data types for user management.
+//!
+
+pub mod account_cache;
+pub mod errors;
+
+use reqwest::Url;
+
+use std::rc::Rc;
+
+use self::errors::*;
+use http::{Response, ResponseWithErr};
+use http::RPCClient;
+
+static BASE_URL: &'static str = "https://api.dropboxapi.com/2/users/";
+
+/// The most account ids get_account_batch accepts at once.
+pub const MAX_ACCOUNT_BATCH_SIZE: usize = 300;
+
+/// A client to interface with the endpoints in the Users namespace of the Dropbox APIs
+#[derive(Debug,Clone)]
+pub struct Users {
+    access_token: Rc<String>,
+}
+
+impl ::http::HasAccessToken for Users {
+    fn access_token(&self) -> &str {
+        self.access_token.as_str()
+    }
+}
+
+impl Users {
+    pub fn new(access_token: Rc<String>) -> Users {
+        Users { access_token: Rc::clone(&access_token) }
+    }
+
+    /// Get information about the current user's account.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#users-get_current_account)
+    pub fn get_current_account(&self) -> Result<Response<FullAccount>> {
+        let url = Url::parse(BASE_URL)?.join("get_current_account")?;
+        let resp_w_err: ResponseWithErr<_, ()> = self.rpc_request(url, ())?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(_) => unreachable!("users: get_current_account has no error"),
+        }
+    }
+
+    /// Get information about a user's account.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#users-get_account)
+    pub fn get_account(&self, account_id: &str) -> Result<Response<BasicAccount>> {
+        let url = Url::parse(BASE_URL)?.join("get_account")?;
+        let resp_w_err = self.rpc_request(url,
+                         &GetAccountArg { account_id: account_id.to_owned() })?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::GetAccountErr(e).into()),
+        }
+    }
+
+    /// Get information about multiple user accounts. At most 300 accounts may be queried per
+    /// request.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#users-get_account_batch)
+    pub fn get_account_batch(&self, account_ids: &[&str]) -> Result<Response<Vec<BasicAccount>>> {
+        get_account_batch(self, account_ids)
+    }
+
+    /// Get the space usage information for the current user's account.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#users-get_space_usage)
+    pub fn get_space_usage(&self) -> Result<Response<SpaceUsage>> {
+        let url = Url::parse(BASE_URL)?.join("get_space_usage")?;
+        let resp_w_err: ResponseWithErr<_, ()> = self.rpc_request(url, ())?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(_) => unreachable!("users: get_space_usage has no error"),
+        }
+    }
+
+    /// Get a list of feature values that may be configured for the current account.
+    ///
+    /// [Dropbox Link](https://www.dropbox.com/developers/documentation/http/documentation#users-features-get_values)
+    pub fn features_get_values(&self,
+                               features: &[UserFeature])
+                               -> Result<Response<UserFeaturesGetValuesBatchResult>> {
+        let url = Url::parse(BASE_URL)?.join("features/get_values")?;
+        let resp_w_err = self.rpc_request(url,
+                         &UserFeaturesGetValuesBatchArg { features: features.to_vec() })?;
+        match resp_w_err {
+            ResponseWithErr::Ok(r) => Ok(r),
+            ResponseWithErr::Err(e) => Err(ErrorKind::UserFeaturesGetValuesBatchErr(e).into()),
+        }
+    }
+}
+
+pub fn get_account_batch<T: RPCClient>(client: &T,
+                                       account_ids: &[&str])
+                                       -> Result<Response<Vec<BasicAccount>>> {
+    let url = Url::parse(BASE_URL)?.join("get_account_batch")?;
+    let resp_w_err = client.rpc_request(url,
+                     &GetAccountBatchArg {
+                         account_ids: account_ids.iter().map(|x| (*x).to_owned()).collect(),
+                     })?;
+    match resp_w_err {
+        ResponseWithErr::Ok(r) => Ok(r),
+        ResponseWithErr::Err(e) => Err(ErrorKind::GetAccountBatchErr(e).into()),
+    }
+}
+
+/**
+ * accounts
+ **/
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct Name {
+    pub given_name: String,
+    pub surname: String,
+    /// Locale-dependent name, usually the given name in the US.
+    pub familiar_name: String,
+    pub display_name: String,
+    pub abbreviated_name: String,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct BasicAccount {
+    pub account_id: String,
+    pub name: Name,
+    pub email: String,
+    pub email_verified: bool,
+    pub disabled: bool,
+    /// Whether this user is a teammate of the current user.
+    pub is_teammate: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_photo_url: Option<String>,
+    /// Only set if the user is a teammate of the current user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_member_id: Option<String>,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum AccountType {
+    Basic,
+    Pro,
+    Business,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum RootInfo {
+    /// The user's root is the team space, with their home folder inside it.
+    Team {
+        root_namespace_id: String,
+        home_namespace_id: String,
+        home_path: String,
+    },
+    User {
+        root_namespace_id: String,
+        home_namespace_id: String,
+    },
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum SharedFolderMemberPolicy {
+    Team,
+    Anyone,
+    Other,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum SharedFolderJoinPolicy {
+    FromTeamOnly,
+    FromAnyone,
+    Other,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum SharedLinkCreatePolicy {
+    DefaultPublic,
+    DefaultTeamOnly,
+    TeamOnly,
+    DefaultNoOne,
+    Other,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum OfficeAddInPolicy {
+    Disabled,
+    Enabled,
+    Other,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct TeamSharingPolicies {
+    pub shared_folder_member_policy: SharedFolderMemberPolicy,
+    pub shared_folder_join_policy: SharedFolderJoinPolicy,
+    pub shared_link_create_policy: SharedLinkCreatePolicy,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct FullTeam {
+    pub id: String,
+    pub name: String,
+    pub sharing_policies: TeamSharingPolicies,
+    pub office_addin_policy: OfficeAddInPolicy,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct FullAccount {
+    pub account_id: String,
+    pub name: Name,
+    pub email: String,
+    pub email_verified: bool,
+    pub disabled: bool,
+    /// The language the user chose for their account, as an IETF language tag.
+    pub locale: String,
+    pub referral_link: String,
+    /// Whether the user has a personal and a work account linked together.
+    pub is_paired: bool,
+    pub account_type: AccountType,
+    pub root_info: RootInfo,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_photo_url: Option<String>,
+    /// The user's two-letter country code, if available.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    /// Only set if the user is a member of a team.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<FullTeam>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_member_id: Option<String>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct GetAccountArg {
+    pub account_id: String,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct GetAccountBatchArg {
+    pub account_ids: Vec<String>,
+}
+
+/**
+ * get_space_usage
+ **/
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum MemberSpaceLimitType {
+    Off,
+    AlertOnly,
+    StopSync,
+    Other,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+pub struct IndividualSpaceAllocation {
+    /// The total space allocated to the user's account, in bytes.
+    pub allocated: u64,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+pub struct TeamSpaceAllocation {
+    /// The total space currently used by the user's team, in bytes.
+    pub used: u64,
+    /// The total space allocated to the user's team, in bytes.
+    pub allocated: u64,
+    /// The total space allocated to the user within its team allocated space, 0 meaning no
+    /// limit.
+    pub user_within_team_space_allocated: u64,
+    pub user_within_team_space_limit_type: MemberSpaceLimitType,
+    pub user_within_team_space_used_cached: u64,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum SpaceAllocation {
+    /// The user's space is allocated individually.
+    Individual(IndividualSpaceAllocation),
+    /// The user shares space with other members of their team.
+    Team(TeamSpaceAllocation),
+    Other,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+pub struct SpaceUsage {
+    /// The user's total space usage, in bytes.
+    pub used: u64,
+    pub allocation: SpaceAllocation,
+}
+
+/**
+ * features/get_values
+ **/
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum UserFeature {
+    PaperAsFiles,
+    FileLocking,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum PaperAsFilesValue {
+    /// Whether Paper docs are stored as files in the user's Dropbox.
+    Enabled { enabled: bool },
+    Other,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum FileLockingValue {
+    Enabled { enabled: bool },
+    Other,
+}
+
+#[derive(PartialEq,Eq,Debug,Copy,Clone,Serialize,Deserialize)]
+#[serde(tag = ".tag", rename_all = "snake_case")]
+pub enum UserFeatureValue {
+    PaperAsFiles { paper_as_files: PaperAsFilesValue },
+    FileLocking { file_locking: FileLockingValue },
+    Other,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct UserFeaturesGetValuesBatchArg {
+    pub features: Vec<UserFeature>,
+}
+
+#[derive(PartialEq,Eq,Debug,Clone,Serialize,Deserialize)]
+pub struct UserFeaturesGetValuesBatchResult {
+    pub values: Vec<UserFeatureValue>,
+}
@@ -0,0 +1,21 @@
+extern crate dropbox_rs;
+extern crate reqwest;
+
+use dropbox_rs::content_hash::hash_reader;
+
+// The test vector published in the Dropbox content hash reference
+// https://www.dropbox.com/developers/reference/content-hash
+const MILKY_WAY_URL: &'static str = "https://www.dropbox.com/static/images/developers/milky-way-nasa.jpg";
+const MILKY_WAY_CONTENT_HASH: &'static str = "485291fa0ee50c016982abbfa943957bcd231aae0492ccbaa22c58e3997b35e0";
+
+#[test]
+fn test_dropbox_reference_content_hash() {
+    let mut image = reqwest::Client::new()
+        .get(MILKY_WAY_URL)
+        .send()
+        .expect("error downloading the reference image");
+    assert!(image.status().is_success());
+
+    let content_hash = hash_reader(&mut image).expect("error hashing the reference image");
+    assert_eq!(content_hash, MILKY_WAY_CONTENT_HASH);
+}
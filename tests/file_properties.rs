@@ -0,0 +1,147 @@
+extern crate dropbox_rs;
+extern crate reqwest;
+extern crate uuid;
+extern crate serde_json;
+extern crate dotenv;
+
+#[path="utils/mod.rs"]
+mod utils;
+
+use std::time::Duration;
+
+use uuid::Uuid;
+
+use dropbox_rs::file_properties::errors::Result;
+use dropbox_rs::file_properties::properties::{PropertyGroup, PropertyGroupUpdate, PropertyField,
+                                              ToPropertyGroup};
+use dropbox_rs::file_properties::templates::PropertyFieldTemplate;
+use dropbox_rs::files::CommitInfo;
+use dropbox_rs::files::temporary_links::upload_to_temporary_link;
+
+use self::utils::get_dropbox_client;
+
+#[test]
+fn test_templates_for_user() {
+    let client = get_dropbox_client();
+
+    let name = format!("dropbox_rs_test_{}", Uuid::new_v4().simple());
+    let added = client.file_properties()
+        .templates_add_for_user(&name,
+                                "Document control",
+                                &[PropertyFieldTemplate::string("status", "Review status")])
+        .expect("error adding template");
+    let template_id = added.body.template_id;
+
+    let listed = client.file_properties()
+        .templates_list_for_user()
+        .expect("error listing templates");
+    assert!(listed.body.template_ids.contains(&template_id));
+
+    client.file_properties()
+        .templates_update_for_user(&template_id)
+        .description("Engineering document control")
+        .add_field(PropertyFieldTemplate::string("owner", "Document owner"))
+        .send()
+        .expect("error updating template");
+
+    let template = client.file_properties()
+        .templates_get_for_user(&template_id)
+        .expect("error getting template");
+    assert_eq!(template.body.name, name);
+    assert_eq!(template.body.description, "Engineering document control");
+    assert_eq!(template.body.fields.len(), 2);
+
+    client.file_properties()
+        .templates_remove_for_user(&template_id)
+        .expect("error removing template");
+}
+
+#[derive(PartialEq,Eq,Debug)]
+struct DocumentControl {
+    status: String,
+    owner: String,
+}
+
+impl ToPropertyGroup for DocumentControl {
+    fn to_property_group(&self, template_id: &str) -> PropertyGroup {
+        PropertyGroup::new(template_id)
+            .with_field("status", &self.status)
+            .with_field("owner", &self.owner)
+    }
+
+    fn from_property_group(group: &PropertyGroup) -> Result<DocumentControl> {
+        Ok(DocumentControl {
+            status: group.required_field("status")?.to_owned(),
+            owner: group.required_field("owner")?.to_owned(),
+        })
+    }
+}
+
+#[test]
+fn test_properties_add_update_search_remove() {
+    let client = get_dropbox_client();
+
+    let template_id = client.file_properties()
+        .templates_add_for_user(&format!("dropbox_rs_test_{}", Uuid::new_v4().simple()),
+                                "Document control",
+                                &[PropertyFieldTemplate::string("status", "Review status"),
+                                  PropertyFieldTemplate::string("owner", "Document owner")])
+        .expect("error adding template")
+        .body
+        .template_id;
+
+    let path = format!("/dropbox_rs_test/{}.txt", Uuid::new_v4());
+    let upload_link = client.files()
+        .get_temporary_upload_link(&CommitInfo::new(&path), Duration::from_secs(60))
+        .expect("error getting temporary upload link");
+    upload_to_temporary_link(&upload_link.body.link, "controlled document")
+        .expect("error uploading to temporary upload link");
+
+    let control = DocumentControl {
+        status: "draft".to_owned(),
+        owner: "alice".to_owned(),
+    };
+    client.file_properties()
+        .properties_add(&path, &[control.to_property_group(&template_id)])
+        .expect("error adding properties");
+
+    let approved = format!("approved_{}", Uuid::new_v4().simple());
+    client.file_properties()
+        .properties_update(&path,
+                           &[PropertyGroupUpdate {
+                                 template_id: template_id.clone(),
+                                 add_or_update_fields: Some(vec![PropertyField {
+                                                                     name: "status".to_owned(),
+                                                                     value: approved.clone(),
+                                                                 }]),
+                                 remove_fields: None,
+                             }])
+        .expect("error updating properties");
+
+    let matches = client.file_properties()
+        .properties_search()
+        .add_query("status", &approved)
+        .template_ids(&[&template_id])
+        .iter()
+        .collect::<Result<Vec<_>>>()
+        .expect("error searching properties");
+    assert_eq!(matches.len(), 1);
+    let group = matches[0].property_group(&template_id).expect("missing property group");
+    assert_eq!(DocumentControl::from_property_group(group).expect("error mapping group"),
+               DocumentControl {
+                   status: approved,
+                   owner: "alice".to_owned(),
+               });
+
+    client.file_properties()
+        .properties_overwrite(&path, &[control.to_property_group(&template_id)])
+        .expect("error overwriting properties");
+    client.file_properties()
+        .properties_remove(&path, &[&template_id])
+        .expect("error removing properties");
+
+    client.files().delete_batch().add_entry(&path).send().expect("error deleting file");
+    client.file_properties()
+        .templates_remove_for_user(&template_id)
+        .expect("error removing template");
+}
@@ -0,0 +1,59 @@
+extern crate dropbox_rs;
+extern crate reqwest;
+extern crate uuid;
+extern crate serde_json;
+extern crate dotenv;
+
+#[path="utils/mod.rs"]
+mod utils;
+
+use uuid::Uuid;
+
+use self::utils::get_dropbox_client;
+
+#[test]
+fn test_file_request_create_update_list_delete() {
+    let client = get_dropbox_client();
+
+    let count_before = client.file_requests()
+        .count()
+        .expect("error counting file requests")
+        .body
+        .file_request_count;
+
+    let title = format!("dropbox_rs_test {}", Uuid::new_v4());
+    let created = client.file_requests()
+        .create(&title, &format!("/dropbox_rs_test/{}", Uuid::new_v4()))
+        .description("Vendor deliverables")
+        .send()
+        .expect("error creating file request");
+    assert!(created.body.is_open);
+
+    let updated = client.file_requests()
+        .update(&created.body.id)
+        .title(&format!("{} (closed)", title))
+        .open(false)
+        .send()
+        .expect("error updating file request");
+    assert!(!updated.body.is_open);
+
+    let fetched = client.file_requests().get(&created.body.id).expect("error getting file request");
+    assert_eq!(fetched.body.title, format!("{} (closed)", title));
+
+    let all = client.file_requests()
+        .list_all()
+        .collect::<Result<Vec<_>, _>>()
+        .expect("error listing file requests");
+    assert!(all.iter().any(|x| x.id == created.body.id));
+    assert_eq!(client.file_requests()
+                   .count()
+                   .expect("error counting file requests")
+                   .body
+                   .file_request_count,
+               count_before + 1);
+
+    let deleted = client.file_requests()
+        .delete(&[&created.body.id])
+        .expect("error deleting file request");
+    assert_eq!(deleted.body.file_requests.len(), 1);
+}
@@ -0,0 +1,377 @@
+extern crate dropbox_rs;
+extern crate reqwest;
+extern crate uuid;
+extern crate serde_json;
+extern crate dotenv;
+extern crate base64;
+
+#[path="utils/mod.rs"]
+mod utils;
+
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::io::Read;
+use std::time::Duration;
+
+use uuid::Uuid;
+
+use dropbox_rs::Dropbox;
+use dropbox_rs::content_hash::ContentHasher;
+use dropbox_rs::files::{FileMetadata, CommitInfo};
+use dropbox_rs::files::locking::{FileLockContent, LockFileResultEntry};
+use dropbox_rs::files::watcher::FolderChanges;
+use dropbox_rs::files::temporary_links::upload_to_temporary_link;
+use dropbox_rs::files::batch::{RelocationBatchResultEntry, DeleteBatchResultEntry};
+use dropbox_rs::files::revisions::ListRevisionsMode;
+use dropbox_rs::files::search::{FileStatus, SearchOrderBy};
+use dropbox_rs::files::thumbnails::{ThumbnailFormat, ThumbnailSize, ThumbnailMode,
+                                    ThumbnailBatchEntry};
+
+use self::utils::get_dropbox_client;
+
+#[test]
+fn test_search_and_continue() {
+    let client = get_dropbox_client();
+
+    let search = client.files()
+        .search("test")
+        .max_results(1)
+        .file_status(FileStatus::Active)
+        .order_by(SearchOrderBy::LastModifiedTime)
+        .include_highlights(true)
+        .send()
+        .expect("error searching files");
+
+    if let Some(cursor) = search.body.cursor {
+        client.files().search_continue(&cursor).expect("error fetching search/continue");
+    }
+}
+
+#[test]
+fn test_search_iter() {
+    let client = get_dropbox_client();
+
+    let matches = client.files()
+        .search("test")
+        .max_results(2)
+        .iter()
+        .take(5)
+        .collect::<Result<Vec<_>, _>>()
+        .expect("error iterating search results");
+
+    for search_match in matches {
+        println!("{:?}", search_match.metadata());
+    }
+}
+
+// A 1x1 PNG image
+const PNG_FIXTURE: &'static str = concat!("iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAQAAAC1HAwCAAAAC0lE",
+                                          "QVR42mNkYAAAAAYAAjCB0C8AAAAASUVORK5CYII=");
+
+/// Uploads `content` to a new file of the test folder and returns its metadata.
+fn upload_fixture(client: &Dropbox, extension: &str, content: Vec<u8>) -> FileMetadata {
+    let path = format!("/dropbox_rs_test/{}.{}", Uuid::new_v4(), extension);
+    let upload_link = client.files()
+        .get_temporary_upload_link(&CommitInfo::new(&path), Duration::from_secs(60))
+        .expect("error getting temporary upload link");
+    upload_to_temporary_link(&upload_link.body.link, content).expect("error uploading fixture");
+    client.files()
+        .get_temporary_link(&path)
+        .expect("error getting fixture metadata")
+        .body
+        .metadata
+}
+
+fn upload_csv_fixture(client: &Dropbox) -> FileMetadata {
+    upload_fixture(client, "csv", b"name,value\nfixture,1\n".to_vec())
+}
+
+fn delete_fixture(client: &Dropbox, file: &FileMetadata) {
+    client.files().delete_batch().add_entry(&file.id).send().expect("error deleting fixture");
+}
+
+#[test]
+fn test_list_revisions_and_download_revision() {
+    let client = get_dropbox_client();
+
+    let file = upload_csv_fixture(&client);
+    let revisions = client.files()
+        .list_revisions(&file.id, ListRevisionsMode::Id, 10)
+        .expect("error listing revisions");
+    assert!(revisions.body.entries.iter().any(|entry| entry.rev == file.rev));
+
+    let mut content = Vec::new();
+    let downloaded = client.files()
+        .download_revision(&file.rev, &mut content)
+        .expect("error downloading revision");
+    assert_eq!(downloaded.rev, file.rev);
+    assert_eq!(content.len() as u64, downloaded.size);
+
+    delete_fixture(&client, &file);
+}
+
+#[test]
+fn test_copy_move_delete_batch() {
+    let client = get_dropbox_client();
+
+    let file = upload_csv_fixture(&client);
+    let folder = format!("/dropbox_rs_test/{}", Uuid::new_v4());
+    let copied_path = format!("{}/copied_{}", folder, file.name);
+    let moved_path = format!("{}/moved_{}", folder, file.name);
+
+    let copied = client.files()
+        .copy_batch()
+        .add_entry(&file.id, &copied_path)
+        .poll_interval(Duration::from_millis(500))
+        .send()
+        .expect("error copying batch");
+    match copied[0] {
+        RelocationBatchResultEntry::Success { .. } => {}
+        ref failure => panic!("failed to copy: {:?}", failure),
+    }
+
+    let moved = client.files()
+        .move_batch()
+        .add_entry(&copied_path, &moved_path)
+        .send()
+        .expect("error moving batch");
+    match moved[0] {
+        RelocationBatchResultEntry::Success { ref success } => {
+            assert_eq!(success.path_lower(), Some(moved_path.to_lowercase().as_str()))
+        }
+        ref failure => panic!("failed to move: {:?}", failure),
+    }
+
+    let deleted = client.files()
+        .delete_batch()
+        .add_entry(&folder)
+        .send()
+        .expect("error deleting batch");
+    match deleted[0] {
+        DeleteBatchResultEntry::Success { .. } => {}
+        ref failure => panic!("failed to delete: {:?}", failure),
+    }
+
+    delete_fixture(&client, &file);
+}
+
+#[test]
+fn test_get_thumbnail_and_batch() {
+    let client = get_dropbox_client();
+
+    let image = upload_fixture(&client, "png", base64::decode(PNG_FIXTURE).unwrap());
+    let mut thumbnail_resp = client.files()
+        .get_thumbnail(&image.id)
+        .format(ThumbnailFormat::Png)
+        .size(ThumbnailSize::W128h128)
+        .mode(ThumbnailMode::Bestfit)
+        .send()
+        .expect("error getting thumbnail");
+    let mut thumbnail = Vec::new();
+    thumbnail_resp.read_to_end(&mut thumbnail).expect("error reading thumbnail");
+    assert!(thumbnail.starts_with(b"\x89PNG"));
+
+    let batch = client.files()
+        .get_thumbnail_batch()
+        .add_entry(&image.id,
+                   ThumbnailFormat::Png,
+                   ThumbnailSize::W128h128,
+                   ThumbnailMode::Bestfit)
+        .send()
+        .expect("error getting thumbnail batch");
+    match batch[0] {
+        ThumbnailBatchEntry::Success { ref thumbnail, .. } => {
+            assert!(thumbnail.starts_with(b"\x89PNG"))
+        }
+        ref failure => panic!("failed to get thumbnail: {:?}", failure),
+    }
+
+    delete_fixture(&client, &image);
+}
+
+#[test]
+fn test_get_preview() {
+    let client = get_dropbox_client();
+
+    let document = upload_csv_fixture(&client);
+    let mut preview_resp = client.files()
+        .get_preview(&document.id, None)
+        .expect("error getting preview");
+    let mut preview = Vec::new();
+    preview_resp.read_to_end(&mut preview).expect("error reading preview");
+    assert!(!preview.is_empty());
+
+    delete_fixture(&client, &document);
+}
+
+#[test]
+fn test_temporary_upload_link_and_temporary_link() {
+    let client = get_dropbox_client();
+
+    let path = format!("/dropbox_rs_test/{}.txt", Uuid::new_v4());
+    let content = format!("uploaded through a temporary upload link {}", Uuid::new_v4());
+
+    let upload_link = client.files()
+        .get_temporary_upload_link(&CommitInfo::new(&path), Duration::from_secs(60))
+        .expect("error getting temporary upload link");
+    let uploaded = upload_to_temporary_link(&upload_link.body.link, content.clone())
+        .expect("error uploading to temporary upload link");
+
+    let mut hasher = ContentHasher::new();
+    hasher.update(content.as_bytes());
+    assert_eq!(uploaded.content_hash, hasher.finish());
+
+    let temporary_link = client.files()
+        .get_temporary_link(&path)
+        .expect("error getting temporary link");
+    assert_eq!(temporary_link.body.metadata.size, content.len() as u64);
+
+    client.files().delete_batch().add_entry(&path).send().expect("error deleting upload");
+}
+
+#[test]
+fn test_save_url() {
+    let client = get_dropbox_client();
+
+    let path = format!("/dropbox_rs_test/{}.png", Uuid::new_v4());
+    let saved = client.files()
+        .save_url(&path, "https://www.rust-lang.org/logos/rust-logo-128x128.png")
+        .poll_interval(Duration::from_millis(500))
+        .max_polls(120)
+        .send()
+        .expect("error saving url");
+    assert_eq!(saved.path_lower, Some(path.to_lowercase()));
+
+    client.files().delete_batch().add_entry(&path).send().expect("error deleting saved url");
+}
+
+#[test]
+fn test_download_zip_to_dir() {
+    let client = get_dropbox_client();
+
+    let folder = format!("/dropbox_rs_test/{}", Uuid::new_v4());
+    let upload_link = client.files()
+        .get_temporary_upload_link(&CommitInfo::new(&format!("{}/zipped.txt", folder)),
+                                   Duration::from_secs(60))
+        .expect("error getting temporary upload link");
+    upload_to_temporary_link(&upload_link.body.link, "zipped content")
+        .expect("error uploading to temporary upload link");
+
+    let destination = env::temp_dir().join(format!("dropbox_rs_{}", Uuid::new_v4()));
+    let (metadata, extracted) = client.files()
+        .download_zip_to_dir(&folder, &destination)
+        .expect("error downloading zip");
+
+    assert_eq!(metadata.path_lower, Some(folder.to_lowercase()));
+    assert_eq!(extracted.len(), 1);
+    assert!(extracted[0].ends_with("zipped.txt"));
+
+    fs::remove_dir_all(&destination).expect("error removing extracted zip");
+    client.files().delete_batch().add_entry(&folder).send().expect("error deleting folder");
+}
+
+#[test]
+fn test_lock_file_guard() {
+    let client = get_dropbox_client();
+
+    let path = format!("/dropbox_rs_test/{}.txt", Uuid::new_v4());
+    let upload_link = client.files()
+        .get_temporary_upload_link(&CommitInfo::new(&path), Duration::from_secs(60))
+        .expect("error getting temporary upload link");
+    upload_to_temporary_link(&upload_link.body.link, "locked content")
+        .expect("error uploading to temporary upload link");
+
+    {
+        let mut guard = client.files().lock_file(&path).expect("error locking file");
+        assert!(guard.lock().single_user().is_some());
+        let refreshed = guard.refresh().expect("error refreshing lock");
+        assert!(refreshed.single_user().is_some());
+    }
+
+    let locks = client.files()
+        .get_file_lock_batch(&[&path])
+        .expect("error getting file locks");
+    match locks.body.entries[0] {
+        LockFileResultEntry::Success { ref lock, .. } => {
+            assert_eq!(lock.content, FileLockContent::Unlocked)
+        }
+        ref failure => panic!("failed to get file lock: {:?}", failure),
+    }
+
+    client.files().delete_batch().add_entry(&path).send().expect("error deleting locked file");
+}
+
+#[test]
+fn test_tags_set_and_get() {
+    let client = get_dropbox_client();
+
+    let path = format!("/dropbox_rs_test/{}.txt", Uuid::new_v4());
+    let upload_link = client.files()
+        .get_temporary_upload_link(&CommitInfo::new(&path), Duration::from_secs(60))
+        .expect("error getting temporary upload link");
+    upload_to_temporary_link(&upload_link.body.link, "tagged content")
+        .expect("error uploading to temporary upload link");
+
+    assert!(client.files().tags_add(&[&path], "not a tag").is_err());
+
+    client.files().tags_add(&[&path], "stale").expect("error adding tag");
+
+    let mut desired = HashMap::new();
+    desired.insert(path.clone(),
+                   vec!["reviewed".to_owned(), "phase_2".to_owned()].into_iter().collect());
+    let changes = client.files().tags_set(&desired).expect("error setting tags");
+    assert_eq!(changes.added.len(), 2);
+    assert_eq!(changes.removed, vec![(path.clone(), "stale".to_owned())]);
+
+    let tags = client.files().tags_get(&[&path]).expect("error getting tags");
+    let mut tag_texts = tags.body.paths_to_tags[0]
+        .tags
+        .iter()
+        .map(|tag| tag.tag_text().to_owned())
+        .collect::<Vec<_>>();
+    tag_texts.sort();
+    assert_eq!(tag_texts, vec!["phase_2", "reviewed"]);
+
+    let changes = client.files().tags_set(&desired).expect("error setting tags again");
+    assert_eq!(changes, Default::default());
+
+    client.files().delete_batch().add_entry(&path).send().expect("error deleting tagged file");
+}
+
+#[test]
+fn test_watch_folder() {
+    let client = get_dropbox_client();
+
+    let folder = format!("/dropbox_rs_test/{}", Uuid::new_v4());
+    let path = format!("{}/watched.txt", folder);
+    let upload_link = client.files()
+        .get_temporary_upload_link(&CommitInfo::new(&format!("{}/existing.txt", folder)),
+                                   Duration::from_secs(60))
+        .expect("error getting temporary upload link");
+    upload_to_temporary_link(&upload_link.body.link, "existing content")
+        .expect("error uploading to temporary upload link");
+
+    let listed = client.files().list_folder(&folder).send().expect("error listing folder");
+    assert_eq!(listed.body.entries.len(), 1);
+
+    let mut watcher = client.files().watch_folder(&folder);
+    watcher.cursor(&listed.body.cursor);
+
+    let upload_link = client.files()
+        .get_temporary_upload_link(&CommitInfo::new(&path), Duration::from_secs(60))
+        .expect("error getting temporary upload link");
+    upload_to_temporary_link(&upload_link.body.link, "watched content")
+        .expect("error uploading to temporary upload link");
+
+    let changes = watcher.next().unwrap().expect("error watching folder");
+    match changes {
+        FolderChanges::Changed(ref entries) => {
+            assert!(entries.iter().any(|x| x.path_lower() == Some(&path.to_lowercase())))
+        }
+        FolderChanges::Reset(_) => panic!("unexpected cursor reset"),
+    }
+    assert!(watcher.current_cursor() != Some(listed.body.cursor.as_str()));
+
+    client.files().delete_batch().add_entry(&folder).send().expect("error deleting folder");
+}
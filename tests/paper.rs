@@ -14,10 +14,13 @@ use std::ops::Index;
 use uuid::Uuid;
 
 use dropbox_rs::Dropbox;
+use dropbox_rs::cursor_store::{CursorStore, MemoryCursorStore};
 use dropbox_rs::paper::{ListPaperDocsSortBy, ImportFormat, ExportFormat, SharingPolicy,
-                        SharingPublicPolicyType, PaperDocUpdatePolicy, PaperDocCreateUpdateResult};
+                        SharingPublicPolicyType, PaperDocUpdatePolicy, PaperDocCreateUpdateResult,
+                        PaperFolderMember};
 use dropbox_rs::paper::users::{MemberSelector, PaperDocPermissionLevel, AddPaperDocUserResult,
-                               UserOnPaperDocFilter};
+                               UserOnPaperDocFilter, PaperDocMember};
+use dropbox_rs::users::account_cache::AccountCache;
 
 use self::utils::get_dropbox_client;
 
@@ -59,7 +62,10 @@ fn test_list_folder_users() {
     let client = get_dropbox_client();
 
     let list = client.paper()
-        .list(None, Some(ListPaperDocsSortBy::Modified), None, 100)
+        .list()
+        .sort_by(ListPaperDocsSortBy::Modified)
+        .limit(100)
+        .send()
         .expect("error fetching list");
     let doc_id = list.body.doc_ids.index(0);
     let folder_users_list = client.paper()
@@ -74,7 +80,10 @@ fn test_paper_list_and_continue() {
     let client = get_dropbox_client();
 
     let list = client.paper()
-        .list(None, Some(ListPaperDocsSortBy::Modified), None, 10)
+        .list()
+        .sort_by(ListPaperDocsSortBy::Modified)
+        .limit(10)
+        .send()
         .expect("error fetching list");
 
     client.paper()
@@ -82,12 +91,50 @@ fn test_paper_list_and_continue() {
         .expect("error fetching list/continue");
 }
 
+#[test]
+fn test_paper_list_all() {
+    let client = get_dropbox_client();
+    let (PaperDocCreateUpdateResult { doc_id, .. }, _) = create_rand_doc(&client);
+
+    let doc_ids = client.paper()
+        .list_all(None, Some(ListPaperDocsSortBy::Modified), None)
+        .collect::<Result<Vec<_>, _>>()
+        .expect("error listing all docs");
+    assert!(doc_ids.contains(&doc_id));
+
+    let current = client.users().get_current_account().expect("error getting current account");
+    let users = client.paper()
+        .users_list_all(&doc_id, UserOnPaperDocFilter::Visited)
+        .collect::<Result<Vec<_>, _>>()
+        .expect("error listing all users");
+    assert!(users.iter().any(|member| match *member {
+        PaperDocMember::User(ref user) => user.user.account_id == current.body.account_id,
+        PaperDocMember::Invitee(_) => false,
+    }));
+
+    let folder_users = client.paper()
+        .list_folder_users_all(&doc_id)
+        .collect::<Result<Vec<_>, _>>()
+        .expect("error listing all folder users");
+    for member in folder_users {
+        match member {
+            PaperFolderMember::User(user) => assert!(user.account_id.starts_with("dbid:")),
+            PaperFolderMember::Invitee(invitee) => assert!(invitee.email.contains('@')),
+        }
+    }
+
+    client.paper().permanently_delete(&doc_id).expect("error deleting doc");
+}
+
 #[test]
 fn test_list_get_folder_info() {
     let client = get_dropbox_client();
 
     let list = client.paper()
-        .list(None, Some(ListPaperDocsSortBy::Modified), None, 100)
+        .list()
+        .sort_by(ListPaperDocsSortBy::Modified)
+        .limit(100)
+        .send()
         .expect("error fetching list");
     let doc_id = list.body.doc_ids.index(0);
     let folder_info = client.paper().get_folder_info(doc_id).expect("error getting folder info");
@@ -168,13 +215,19 @@ fn test_users_add_list_remove() {
     println!("{:?}", users_add_result);
 
     let users_list = client.paper()
-        .users_list(&doc_id, 10, UserOnPaperDocFilter::Shared)
+        .users_list(&doc_id)
+        .limit(10)
+        .filter_by(UserOnPaperDocFilter::Shared)
+        .send()
         .expect("error listing users");
 
     client.paper().users_remove(&doc_id, &member_selector).expect("error removing user");
 
     let users_list_after_remove = client.paper()
-        .users_list(&doc_id, 10, UserOnPaperDocFilter::Shared)
+        .users_list(&doc_id)
+        .limit(10)
+        .filter_by(UserOnPaperDocFilter::Shared)
+        .send()
         .expect("error listing users");
 
     client.paper().permanently_delete(&doc_id).expect("error permanently deleting doc");
@@ -187,3 +240,70 @@ fn test_users_add_list_remove() {
                "jfokkan@gmail.com");
     assert_eq!(users_list_after_remove.body.invitees.len(), 0);
 }
+
+#[test]
+fn test_paper_list_all_checkpoint() {
+    let client = get_dropbox_client();
+    let store = MemoryCursorStore::new();
+    let (PaperDocCreateUpdateResult { doc_id: first_doc_id, .. }, _) = create_rand_doc(&client);
+    let (PaperDocCreateUpdateResult { doc_id: second_doc_id, .. }, _) = create_rand_doc(&client);
+
+    // With pages of a single doc, the cursor of the first page is saved once its doc was yielded
+    // and the next one is requested
+    let mut first_run = client.paper()
+        .list()
+        .limit(1)
+        .iter()
+        .checkpoint(store.clone(), "paper_docs")
+        .expect("error loading checkpoint");
+    let consumed = first_run.next()
+        .expect("no docs listed")
+        .expect("error listing docs");
+    first_run.next().expect("only one doc listed").expect("error listing docs");
+
+    let resumed = client.paper()
+        .list()
+        .limit(1)
+        .iter()
+        .checkpoint(store.clone(), "paper_docs")
+        .expect("error loading checkpoint")
+        .collect::<Result<Vec<_>, _>>()
+        .expect("error resuming listing");
+    assert!(!resumed.contains(&consumed));
+    assert_eq!(store.load("paper_docs").expect("error loading cursor"), None);
+
+    client.paper().permanently_delete(&first_doc_id).expect("error permanently deleting doc");
+    client.paper().permanently_delete(&second_doc_id).expect("error permanently deleting doc");
+}
+
+#[test]
+fn test_users_list_with_accounts() {
+    let client = get_dropbox_client();
+    let cache = AccountCache::new();
+
+    let (PaperDocCreateUpdateResult { doc_id, .. }, _) = create_rand_doc(&client);
+    let member_selector = MemberSelector::Email { email: "jfokkan@gmail.com".to_owned() };
+    client.paper()
+        .users_add(&doc_id)
+        .quiet(true)
+        .add_member(&member_selector, &PaperDocPermissionLevel::ViewAndComment)
+        .send()
+        .expect("error adding users");
+
+    let collaborators = client.paper()
+        .users_list_with_accounts(&doc_id, Some(UserOnPaperDocFilter::Shared), &cache)
+        .expect("error listing users with accounts");
+    let current = client.users().get_current_account().expect("error getting current account");
+
+    client.paper().permanently_delete(&doc_id).expect("error permanently deleting doc");
+
+    let owner = collaborators.iter().find(|x| x.is_owner).expect("owner not listed");
+    assert_eq!(owner.account_id, Some(current.body.account_id.clone()));
+    assert_eq!(owner.email, Some(current.body.email.clone()));
+    assert_eq!(owner.display_name(), Some(current.body.name.display_name.as_str()));
+    assert!(collaborators.iter().any(|x| {
+        x.email == Some("jfokkan@gmail.com".to_owned()) &&
+        x.permission_level == Some(PaperDocPermissionLevel::ViewAndComment)
+    }));
+    assert!(cache.get(&current.body.account_id).is_some());
+}
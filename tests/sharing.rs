@@ -0,0 +1,250 @@
+extern crate dropbox_rs;
+extern crate reqwest;
+extern crate uuid;
+extern crate serde_json;
+extern crate dotenv;
+
+#[path="utils/mod.rs"]
+mod utils;
+
+use std::env;
+use std::fs::{self, File};
+use std::io::Read;
+use std::time::Duration;
+
+use uuid::Uuid;
+
+use dropbox_rs::files::CommitInfo;
+use dropbox_rs::files::temporary_links::upload_to_temporary_link;
+use dropbox_rs::sharing::links::{SharedLinkSettings, RequestedVisibility};
+use dropbox_rs::sharing::folders::{AccessLevel, AclUpdatePolicy};
+use dropbox_rs::sharing::members::{MemberSelector, MembershipInfo, FileMemberActionIndividualResult,
+                                   ListFileMembersIndividualResult};
+
+use self::utils::get_dropbox_client;
+
+#[test]
+fn test_shared_link_create_list_modify_revoke() {
+    let client = get_dropbox_client();
+
+    let path = format!("/dropbox_rs_test/{}/shared.txt", Uuid::new_v4());
+    let upload_link = client.files()
+        .get_temporary_upload_link(&CommitInfo::new(&path), Duration::from_secs(60))
+        .expect("error getting temporary upload link");
+    upload_to_temporary_link(&upload_link.body.link, "shared content")
+        .expect("error uploading to temporary upload link");
+
+    let created = client.sharing()
+        .create_shared_link_with_settings(&path)
+        .send()
+        .expect("error creating shared link");
+    let existing = client.sharing()
+        .get_or_create_shared_link(&path)
+        .expect("error getting existing shared link");
+    assert_eq!(existing.url(), created.body.url());
+
+    let links = client.sharing()
+        .list_shared_links()
+        .path(&path)
+        .direct_only(true)
+        .iter()
+        .collect::<Result<Vec<_>, _>>()
+        .expect("error listing shared links");
+    assert!(links.iter().any(|x| x.url() == created.body.url()));
+
+    let modified = client.sharing()
+        .modify_shared_link_settings(created.body.url(),
+                                     &SharedLinkSettings {
+                                         requested_visibility: Some(RequestedVisibility::Public),
+                                         ..SharedLinkSettings::default()
+                                     },
+                                     true)
+        .expect("error modifying shared link");
+    assert_eq!(modified.body.expires(), None);
+
+    client.sharing().revoke_shared_link(created.body.url()).expect("error revoking shared link");
+    client.files().delete_batch().add_entry(&path).send().expect("error deleting shared file");
+}
+
+#[test]
+fn test_mirror_shared_link() {
+    let client = get_dropbox_client();
+
+    let folder = format!("/dropbox_rs_test/{}", Uuid::new_v4());
+    for &(path, content) in &[("top.txt", "top content"), ("nested/inner.txt", "inner content")] {
+        let upload_link = client.files()
+            .get_temporary_upload_link(&CommitInfo::new(&format!("{}/{}", folder, path)),
+                                       Duration::from_secs(60))
+            .expect("error getting temporary upload link");
+        upload_to_temporary_link(&upload_link.body.link, content)
+            .expect("error uploading to temporary upload link");
+    }
+
+    let link = client.sharing()
+        .get_or_create_shared_link(&folder)
+        .expect("error creating shared link");
+    let metadata = client.sharing()
+        .get_shared_link_metadata(link.url(), Some("/nested/inner.txt"), None)
+        .expect("error getting shared link metadata");
+    assert_eq!(metadata.body.name(), "inner.txt");
+
+    let mut inner = String::new();
+    client.sharing()
+        .get_shared_link_file(link.url(), Some("/nested/inner.txt"), None)
+        .expect("error getting shared link file")
+        .read_to_string(&mut inner)
+        .expect("error reading shared link file");
+    assert_eq!(inner, "inner content");
+
+    let destination = env::temp_dir().join(format!("dropbox_rs_{}", Uuid::new_v4()));
+    let mut mirrored = client.sharing()
+        .mirror_shared_link(link.url(), None, &destination)
+        .expect("error mirroring shared link");
+    mirrored.sort();
+    assert_eq!(mirrored,
+               vec![destination.join("nested").join("inner.txt"), destination.join("top.txt")]);
+    let mut top = String::new();
+    File::open(destination.join("top.txt"))
+        .and_then(|mut f| f.read_to_string(&mut top))
+        .expect("error reading mirrored file");
+    assert_eq!(top, "top content");
+
+    fs::remove_dir_all(&destination).expect("error removing mirrored folder");
+    client.sharing().revoke_shared_link(link.url()).expect("error revoking shared link");
+    client.files().delete_batch().add_entry(&folder).send().expect("error deleting shared folder");
+}
+
+#[test]
+fn test_share_update_list_unshare_folder() {
+    let client = get_dropbox_client();
+
+    let folder = format!("/dropbox_rs_test/{}", Uuid::new_v4());
+    let upload_link = client.files()
+        .get_temporary_upload_link(&CommitInfo::new(&format!("{}/brief.txt", folder)),
+                                   Duration::from_secs(60))
+        .expect("error getting temporary upload link");
+    upload_to_temporary_link(&upload_link.body.link, "engagement brief")
+        .expect("error uploading to temporary upload link");
+
+    let shared = client.sharing()
+        .share_folder(&folder)
+        .force_async(true)
+        .acl_update_policy(AclUpdatePolicy::Owner)
+        .send()
+        .expect("error sharing folder");
+    assert_eq!(shared.access_type, AccessLevel::Owner);
+
+    let updated = client.sharing()
+        .update_folder_policy(&shared.shared_folder_id)
+        .acl_update_policy(AclUpdatePolicy::Editors)
+        .send()
+        .expect("error updating folder policy");
+    assert_eq!(updated.body.policy.acl_update_policy, AclUpdatePolicy::Editors);
+
+    let metadata = client.sharing()
+        .get_folder_metadata(&shared.shared_folder_id)
+        .expect("error getting folder metadata");
+    assert_eq!(metadata.body.name, shared.name);
+
+    let folders = client.sharing()
+        .list_folders()
+        .limit(1)
+        .iter()
+        .collect::<Result<Vec<_>, _>>()
+        .expect("error listing shared folders");
+    assert!(folders.iter().any(|x| x.shared_folder_id == shared.shared_folder_id));
+
+    client.sharing()
+        .unshare_folder(&shared.shared_folder_id, false)
+        .expect("error unsharing folder");
+    client.files().delete_batch().add_entry(&folder).send().expect("error deleting shared folder");
+}
+
+#[test]
+fn test_set_folder_members() {
+    let client = get_dropbox_client();
+
+    let folder = format!("/dropbox_rs_test/{}", Uuid::new_v4());
+    let upload_link = client.files()
+        .get_temporary_upload_link(&CommitInfo::new(&format!("{}/brief.txt", folder)),
+                                   Duration::from_secs(60))
+        .expect("error getting temporary upload link");
+    upload_to_temporary_link(&upload_link.body.link, "engagement brief")
+        .expect("error uploading to temporary upload link");
+    let shared = client.sharing().share_folder(&folder).send().expect("error sharing folder");
+
+    let member = MemberSelector::Email { email: "jfokkan@gmail.com".to_owned() };
+    let changes = client.sharing()
+        .set_folder_members(&shared.shared_folder_id,
+                            &[(member.clone(), AccessLevel::Viewer)])
+        .expect("error setting folder members");
+    assert_eq!(changes.added, vec![(member.clone(), AccessLevel::Viewer)]);
+
+    let changes = client.sharing()
+        .set_folder_members(&shared.shared_folder_id,
+                            &[(member.clone(), AccessLevel::Editor)])
+        .expect("error setting folder members");
+    assert_eq!(changes.updated, vec![(member.clone(), AccessLevel::Editor)]);
+
+    let members = client.sharing()
+        .list_folder_members(&shared.shared_folder_id)
+        .iter()
+        .collect::<Result<Vec<_>, _>>()
+        .expect("error listing folder members");
+    assert!(members.iter().any(|x| match *x {
+        MembershipInfo::User(ref user) => user.access_type == AccessLevel::Owner,
+        _ => false,
+    }));
+
+    let changes = client.sharing()
+        .set_folder_members(&shared.shared_folder_id, &[])
+        .expect("error setting folder members");
+    assert_eq!(changes.removed.len(), 1);
+
+    client.sharing()
+        .unshare_folder(&shared.shared_folder_id, false)
+        .expect("error unsharing folder");
+    client.files().delete_batch().add_entry(&folder).send().expect("error deleting shared folder");
+}
+
+#[test]
+fn test_file_members() {
+    let client = get_dropbox_client();
+
+    let path = format!("/dropbox_rs_test/{}/contract.txt", Uuid::new_v4());
+    let upload_link = client.files()
+        .get_temporary_upload_link(&CommitInfo::new(&path), Duration::from_secs(60))
+        .expect("error getting temporary upload link");
+    upload_to_temporary_link(&upload_link.body.link, "contract draft")
+        .expect("error uploading to temporary upload link");
+
+    let member = MemberSelector::Email { email: "jfokkan@gmail.com".to_owned() };
+    let added = client.sharing()
+        .add_file_member(&path)
+        .add_member(&member)
+        .quiet(true)
+        .send()
+        .expect("error adding file member");
+    match added.body[0].result {
+        FileMemberActionIndividualResult::Success { .. } => {}
+        ref result => panic!("unexpected add_file_member result {:?}", result),
+    }
+
+    let members = client.sharing()
+        .list_file_members(&path)
+        .iter()
+        .collect::<Result<Vec<_>, _>>()
+        .expect("error listing file members");
+    assert!(members.len() >= 2);
+
+    let batch = client.sharing()
+        .list_file_members_batch(&[&path], 10)
+        .expect("error listing file members in batch");
+    match batch.body[0].result {
+        ListFileMembersIndividualResult::Result(ref result) => assert!(result.member_count >= 2),
+        ref result => panic!("unexpected list_file_members/batch result {:?}", result),
+    }
+
+    client.sharing().remove_file_member_2(&path, &member).expect("error removing file member");
+    client.files().delete_batch().add_entry(&path).send().expect("error deleting shared file");
+}
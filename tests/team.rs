@@ -0,0 +1,50 @@
+extern crate dropbox_rs;
+extern crate reqwest;
+extern crate uuid;
+extern crate serde_json;
+extern crate dotenv;
+
+#[path="utils/mod.rs"]
+mod utils;
+
+use dropbox_rs::team::members::{MembersGetInfoItemV2, UserSelectorArg};
+
+use self::utils::get_dropbox_team_client;
+
+#[test]
+fn test_get_info() {
+    let client = get_dropbox_team_client();
+
+    let info = client.team().get_info().expect("error getting team info");
+    assert!(info.body.num_provisioned_users > 0);
+}
+
+#[test]
+fn test_members_list_and_get_info() {
+    let client = get_dropbox_team_client();
+
+    let members = client.team()
+        .members_list()
+        .limit(2)
+        .iter()
+        .collect::<Result<Vec<_>, _>>()
+        .expect("error listing team members");
+    assert!(!members.is_empty());
+
+    let profile = &members[0].profile;
+    let selectors = vec![UserSelectorArg::TeamMemberId {
+                             team_member_id: profile.team_member_id.clone(),
+                         },
+                         UserSelectorArg::Email { email: "not-a-member@example.com".to_owned() }];
+    let info = client.team().members_get_info(&selectors).expect("error getting members info");
+    match info.body.members_info[0] {
+        MembersGetInfoItemV2::MemberInfo(ref member) => {
+            assert_eq!(member.profile.email, profile.email)
+        }
+        ref item => panic!("unexpected members info {:?}", item),
+    }
+    match info.body.members_info[1] {
+        MembersGetInfoItemV2::IdNotFound { .. } => {}
+        ref item => panic!("unexpected members info {:?}", item),
+    }
+}
@@ -0,0 +1,54 @@
+extern crate dropbox_rs;
+extern crate reqwest;
+extern crate uuid;
+extern crate serde_json;
+extern crate dotenv;
+
+#[path="utils/mod.rs"]
+mod utils;
+
+use dropbox_rs::users::{SpaceAllocation, UserFeature, UserFeatureValue};
+
+use self::utils::get_dropbox_client;
+
+#[test]
+fn test_get_current_account_and_batch() {
+    let client = get_dropbox_client();
+
+    let current = client.users().get_current_account().expect("error getting current account");
+    let account = client.users()
+        .get_account(&current.body.account_id)
+        .expect("error getting account");
+    assert_eq!(account.body.email, current.body.email);
+
+    let batch = client.users()
+        .get_account_batch(&[&current.body.account_id])
+        .expect("error getting account batch");
+    assert_eq!(batch.body.len(), 1);
+    assert_eq!(batch.body[0].name, current.body.name);
+}
+
+#[test]
+fn test_get_space_usage() {
+    let client = get_dropbox_client();
+
+    let usage = client.users().get_space_usage().expect("error getting space usage");
+    match usage.body.allocation {
+        SpaceAllocation::Individual(allocation) => assert!(allocation.allocated >= usage.body.used),
+        SpaceAllocation::Team(allocation) => assert!(allocation.allocated > 0),
+        SpaceAllocation::Other => {}
+    }
+}
+
+#[test]
+fn test_features_get_values() {
+    let client = get_dropbox_client();
+
+    let values = client.users()
+        .features_get_values(&[UserFeature::PaperAsFiles])
+        .expect("error getting feature values");
+    match values.body.values[0] {
+        UserFeatureValue::PaperAsFiles { .. } => {}
+        ref value => panic!("unexpected feature value {:?}", value),
+    }
+}
@@ -7,7 +7,7 @@ use std::env;
 
 use dotenv::dotenv;
 
-use dropbox_rs::Dropbox;
+use dropbox_rs::{Dropbox, DropboxTeam};
 
 pub fn get_dropbox_client() -> Dropbox {
     dotenv().ok();
@@ -27,3 +27,13 @@ pub fn get_dropbox_client_revokable() -> Dropbox {
         .expect("Couldn't find DROPBOX_TOKEN_REVOKABLE env_var");
     Dropbox::new(&access_code)
 }
+
+pub fn get_dropbox_team_client() -> DropboxTeam {
+    dotenv().ok();
+    // Ignore error on init since it might be called several times during tests
+    let _ = env_logger::init();
+
+    let access_code = env::var("DROPBOX_TEAM_TOKEN")
+        .expect("Couldn't find DROPBOX_TEAM_TOKEN env_var");
+    DropboxTeam::new(&access_code)
+}